@@ -1,7 +1,7 @@
-use std::{marker::PhantomData, panic::AssertUnwindSafe};
+use std::{marker::PhantomData, panic::AssertUnwindSafe, rc::Rc};
 
 use crate::{
-    innerlude::Scoped,
+    innerlude::{ComponentPanicked, ErrorBoundary, Scoped},
     nodes::{ComponentReturn, RenderReturn},
     scopes::{Scope, ScopeState},
     Element,
@@ -74,7 +74,15 @@ where
 
         match res {
             Ok(e) => e,
-            Err(_) => RenderReturn::default(),
+            Err(payload) => {
+                // Hand the panic to the nearest error boundary and leave a placeholder where the component was
+                let error = ComponentPanicked::new(cx.scope_id(), payload);
+                log::error!("{}", error);
+                if let Some(boundary) = cx.consume_context::<Rc<ErrorBoundary>>() {
+                    boundary.insert_panic(error);
+                }
+                RenderReturn::default()
+            }
         }
     }
 }
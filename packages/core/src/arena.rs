@@ -1,8 +1,8 @@
-use std::ptr::NonNull;
+use std::{ptr::NonNull, rc::Rc};
 
 use crate::{
     innerlude::DirtyScope, nodes::RenderReturn, nodes::VNode, virtual_dom::VirtualDom,
-    AttributeValue, DynamicNode, ScopeId,
+    AttributeValue, DynamicNode, ScopeId, SuspenseContext,
 };
 use bumpalo::boxed::Box as BumpBox;
 
@@ -20,6 +20,13 @@ pub(crate) struct ElementRef {
 
     // The actual template
     pub template: Option<NonNull<VNode<'static>>>,
+
+    // The scope that rendered the template
+    pub scope: ScopeId,
+
+    // The render of the scope that allocated the template, used to catch references to templates that have been freed
+    #[cfg(debug_assertions)]
+    pub generation: usize,
 }
 
 #[derive(Clone, Copy)]
@@ -33,6 +40,9 @@ impl ElementRef {
         Self {
             template: None,
             path: ElementPath::Root(0),
+            scope: ScopeId(0),
+            #[cfg(debug_assertions)]
+            generation: 0,
         }
     }
 }
@@ -55,6 +65,10 @@ impl VirtualDom {
     }
 
     fn next_reference(&mut self, template: &VNode, path: ElementPath) -> ElementId {
+        let scope = self.scope_stack.last().copied().unwrap_or(ScopeId(0));
+        #[cfg(debug_assertions)]
+        let generation = self.current_generation(scope);
+
         let entry = self.elements.vacant_entry();
         let id = entry.key();
 
@@ -62,6 +76,9 @@ impl VirtualDom {
             // We know this is non-null because it comes from a reference
             template: Some(unsafe { NonNull::new_unchecked(template as *const _ as *mut _) }),
             path,
+            scope,
+            #[cfg(debug_assertions)]
+            generation,
         });
         ElementId(id)
     }
@@ -83,10 +100,55 @@ impl VirtualDom {
     }
 
     pub(crate) fn update_template(&mut self, el: ElementId, node: &VNode) {
+        #[cfg(debug_assertions)]
+        {
+            let scope = self.elements[el.0].scope;
+            self.elements[el.0].generation = self.current_generation(scope);
+        }
+
         let node: *const VNode = node as *const _;
         self.elements[el.0].template = unsafe { std::mem::transmute(node) };
     }
 
+    /// Load the template an element lives in.
+    ///
+    /// Templates live in the bump frames of the scope that rendered them, and a frame is reset every other render. In
+    /// debug builds, this panics if the template was allocated in a frame that has been reset since, instead of reading
+    /// freed memory.
+    pub(crate) fn element_template<'a>(
+        &'a self,
+        el: ElementId,
+    ) -> Option<(&'a ElementRef, &'a VNode<'a>)> {
+        let el_ref = self.elements.get(el.0)?;
+        let template = el_ref.template?;
+
+        #[cfg(debug_assertions)]
+        {
+            let current = self.current_generation(el_ref.scope);
+            assert!(
+                current.saturating_sub(el_ref.generation) <= 1,
+                "{:?} references a template from render {} of {:?}, but the scope has rendered {} times since, so its frame was reset. This is a bug in the reconciler - an ElementRef should have been updated or reclaimed when its template was diffed.",
+                el,
+                el_ref.generation,
+                el_ref.scope,
+                current - el_ref.generation,
+            );
+        }
+
+        // safety: we maintain references of all vnodes in the element slab, and check they're alive in debug builds
+        let template = unsafe { &*(template.as_ptr() as *const VNode<'a>) };
+        Some((el_ref, template))
+    }
+
+    /// The number of times the scope has rendered, which identifies the frame its latest nodes were allocated in
+    #[cfg(debug_assertions)]
+    fn current_generation(&self, scope: ScopeId) -> usize {
+        self.scopes
+            .get(scope)
+            .map(|scope| scope.render_cnt.get())
+            .unwrap_or_default()
+    }
+
     // Drop a scope and all its children
     //
     // Note: This will not remove any ids from the arena
@@ -106,6 +168,15 @@ impl VirtualDom {
             }
         }
 
+        // A boundary that's removed while it's suspended takes the scope of its fallback with it
+        let fallback = self.scopes[id]
+            .has_context::<Rc<SuspenseContext>>()
+            .filter(|boundary| boundary.id == id)
+            .and_then(|boundary| boundary.fallback_scope.take());
+        if let Some(fallback) = fallback {
+            self.drop_scope(fallback, true);
+        }
+
         let scope = &mut self.scopes[id];
 
         // Drop all the hooks once the children are dropped
@@ -175,10 +246,11 @@ impl VirtualDom {
 }
 
 impl ElementPath {
-    pub(crate) fn is_ascendant(&self, big: &&[u8]) -> bool {
+    /// Check if this path is the same as, or is nested inside of, the given path
+    pub(crate) fn is_decendant(&self, small: &&[u8]) -> bool {
         match *self {
-            ElementPath::Deep(small) => small.len() <= big.len() && small == &big[..small.len()],
-            ElementPath::Root(r) => big.len() == 1 && big[0] == r as u8,
+            ElementPath::Deep(big) => small.len() <= big.len() && *small == &big[..small.len()],
+            ElementPath::Root(r) => small.len() == 1 && small[0] == r as u8,
         }
     }
 }
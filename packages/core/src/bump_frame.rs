@@ -2,6 +2,19 @@ use crate::nodes::RenderReturn;
 use bumpalo::Bump;
 use std::cell::{Cell, UnsafeCell};
 
+/// How many bytes the bump frames of a [`crate::VirtualDom`] have allocated and reused, for
+/// [`crate::VirtualDom::frame_stats`]
+#[cfg(feature = "frame-stats")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    /// Bytes the frames had to get from the allocator, either when they were first used or because a render didn't
+    /// fit into the memory they already had
+    pub allocated: usize,
+
+    /// Bytes that were reset after an earlier render and used again, instead of being allocated from scratch
+    pub reused: usize,
+}
+
 pub(crate) struct BumpFrame {
     pub bump: UnsafeCell<Bump>,
     pub node: Cell<*const RenderReturn<'static>>,
@@ -1,11 +1,13 @@
-use crate::any_props::AnyProps;
+use crate::any_props::{AnyProps, VProps};
 use crate::innerlude::{BorrowedAttributeValue, VComponent, VPlaceholder, VText};
 use crate::mutations::Mutation;
 use crate::mutations::Mutation::*;
 use crate::nodes::VNode;
 use crate::nodes::{DynamicNode, TemplateNode};
 use crate::virtual_dom::VirtualDom;
-use crate::{AttributeValue, ElementId, RenderReturn, ScopeId, SuspenseContext, Template};
+use crate::{
+    AttributeValue, Component, ElementId, RenderReturn, ScopeId, SuspenseContext, Template,
+};
 use std::cell::Cell;
 use std::iter::Peekable;
 use std::rc::Rc;
@@ -554,13 +556,7 @@ impl<'b> VirtualDom {
             _ => return created,
         };
 
-        // Since this is a boundary, use its placeholder within the template as the placeholder for the suspense tree
-        let new_id = self.next_element(new, parent.template.get().node_paths[idx]);
-
-        // Now connect everything to the boundary
-        self.scopes[scope].placeholder.set(Some(new_id));
-
-        // This involves breaking off the mutations to this point, and then creating a new placeholder for the boundary
+        // Break off the mutations to this point, they're held back until the tree is ready
         // Note that we break off dynamic mutations only - since static mutations aren't rendered immediately
         let split_off = unsafe {
             std::mem::transmute::<Vec<Mutation>, Vec<Mutation>>(
@@ -574,6 +570,18 @@ impl<'b> VirtualDom {
             .borrow_mut()
             .extend(self.collected_leaves.drain(..));
 
+        // Show the fallback until the tree is ready, it's swapped out once the tree resolves
+        if let Some(fallback) = boundary.fallback {
+            return self.mount_fallback(scope, &boundary, fallback, parent);
+        }
+
+        // Otherwise, use the boundary's placeholder within the template as the placeholder for the suspense tree
+        let new_id = self.next_element(new, parent.template.get().node_paths[idx]);
+
+        // Now connect everything to the boundary
+        self.scopes[scope].placeholder.set(Some(new_id));
+        boundary.placeholder.set(Some(new_id));
+
         // Now assign the placeholder in the DOM
         self.mutations.push(AssignId {
             id: new_id,
@@ -583,6 +591,36 @@ impl<'b> VirtualDom {
         0
     }
 
+    /// Mount the fallback of a suspense boundary in place of its suspended tree
+    fn mount_fallback(
+        &mut self,
+        boundary_scope: ScopeId,
+        boundary: &SuspenseContext,
+        fallback: Component,
+        parent: &'b VNode<'b>,
+    ) -> usize {
+        use RenderReturn::*;
+
+        // The fallback is rendered as a child of the boundary, so it can read the boundary's contexts
+        self.scope_stack.push(boundary_scope);
+        let scope = self
+            .new_scope(
+                Box::new(VProps::new(fallback, |_, _| unreachable!(), ())),
+                "fallback",
+            )
+            .id;
+        boundary.fallback_scope.set(Some(scope));
+
+        let created = match unsafe { self.run_scope(scope).extend_lifetime_ref() } {
+            Ready(t) => self.create_scope(scope, t),
+            Aborted(t) => self.mount_aborted(parent, t),
+            Pending(_) => panic!("Suspense fallbacks can't be async components"),
+        };
+        self.scope_stack.pop();
+
+        created
+    }
+
     fn mount_aborted(&mut self, parent: &'b VNode<'b>, placeholder: &VPlaceholder) -> usize {
         let id = self.next_element(parent, &[]);
         self.mutations.push(Mutation::CreatePlaceholder { id });
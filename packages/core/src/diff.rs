@@ -4,13 +4,14 @@ use crate::{
     innerlude::{BorrowedAttributeValue, DirtyScope, VComponent, VPlaceholder, VText},
     mutations::Mutation,
     nodes::RenderReturn,
-    nodes::{DynamicNode, VNode},
+    nodes::{str_eq, DynamicNode, VNode},
     scopes::ScopeId,
     virtual_dom::VirtualDom,
-    Attribute, AttributeValue, TemplateNode,
+    Attribute, TemplateNode,
 };
 
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::ptr::NonNull;
 use DynamicNode::*;
 
 impl<'b> VirtualDom {
@@ -125,10 +126,9 @@ impl<'b> VirtualDom {
                     .mounted_element
                     .set(left_attr.mounted_element.get());
 
-                // We want to make sure anything listener that gets pulled is valid
-                if let AttributeValue::Listener(_) = right_attr.value {
-                    self.update_template(left_attr.mounted_element.get(), right_template);
-                }
+                // We want to make sure anything listener that gets pulled is valid, and that the element doesn't
+                // keep pointing at the old template once its frame is reset
+                self.update_template(left_attr.mounted_element.get(), right_template);
 
                 // If the attributes are different (or volatile), we need to update them
                 if left_attr.value != right_attr.value || left_attr.volatile {
@@ -148,6 +148,18 @@ impl<'b> VirtualDom {
 
         // Make sure the roots get transferred over while we're here
         right_template.root_ids.transfer(&left_template.root_ids);
+
+        // Roots that are elements of this template should point at the new template too. Dynamic roots belong to the
+        // templates of the nodes they were created by, so leave those alone.
+        for idx in 0..right_template.root_ids.len() {
+            if let Some(id) = right_template.root_ids.get(idx) {
+                let points_at_left = self.elements.get(id.0).and_then(|el| el.template)
+                    == Some(NonNull::from(left_template).cast());
+                if points_at_left {
+                    self.update_template(id, right_template);
+                }
+            }
+        }
     }
 
     fn diff_dynamic_node(
@@ -202,7 +214,9 @@ impl<'b> VirtualDom {
         right.scope.set(Some(scope_id));
 
         // copy out the box for both
-        let old = self.scopes[scope_id].props.as_ref();
+        // props that were deferred while the scope was offscreen are newer than the ones it last rendered with
+        let scope = &self.scopes[scope_id];
+        let old = scope.deferred_props.as_ref().or(scope.props.as_ref());
         let new: Box<dyn AnyProps> = right.props.take().unwrap();
         let new: Box<dyn AnyProps> = unsafe { std::mem::transmute(new) };
 
@@ -213,8 +227,20 @@ impl<'b> VirtualDom {
             return;
         }
 
+        // Offscreen scopes hold on to the new props and render with them once they are visible again
+        // Only static props can outlive the parent's render, so borrowed props are always rendered immediately
+        if left.static_props && self.scopes[scope_id].offscreen.get() {
+            let scope = &mut self.scopes[scope_id];
+            scope.deferred_props = Some(new);
+            scope.render_deferred.set(true);
+            return;
+        }
+
         // First, move over the props from the old to the new, dropping old props in the process
-        self.scopes[scope_id].props = Some(new);
+        // Any props that were deferred while the scope was offscreen are older than these, so they're dropped too
+        let scope = &mut self.scopes[scope_id];
+        scope.props = Some(new);
+        scope.deferred_props = None;
 
         // Now run the component and diff it
         self.run_scope(scope_id);
@@ -308,31 +334,70 @@ impl<'b> VirtualDom {
             .unwrap_or_else(|| self.next_element(node, &[0]));
 
         right.id.set(Some(id));
-        if left.value != right.value {
+        if !str_eq(left.value, right.value) {
             let value = unsafe { std::mem::transmute(right.value) };
             self.mutations.push(Mutation::SetText { id, value });
         }
     }
 
     fn diff_non_empty_fragment(&mut self, old: &'b [VNode<'b>], new: &'b [VNode<'b>]) {
-        let new_is_keyed = new[0].key.is_some();
-        let old_is_keyed = old[0].key.is_some();
-        debug_assert!(
-            new.iter().all(|n| n.key.is_some() == new_is_keyed),
-            "all siblings must be keyed or all siblings must be non-keyed"
-        );
-        debug_assert!(
-            old.iter().all(|o| o.key.is_some() == old_is_keyed),
-            "all siblings must be keyed or all siblings must be non-keyed"
-        );
-
-        if new_is_keyed && old_is_keyed {
+        // Nodes can only be matched up by key if every sibling on both sides has a unique key
+        if self.has_unique_keys(old) && self.has_unique_keys(new) {
             self.diff_keyed_children(old, new);
         } else {
             self.diff_non_keyed_children(old, new);
         }
     }
 
+    /// Check if every sibling in a list has a key, and no two siblings share the same key
+    ///
+    /// Lists that mix keyed and unkeyed siblings, or repeat a key, can't be reordered by key, so we warn and diff them
+    /// by position instead. Each template and key is only warned about once.
+    fn has_unique_keys(&mut self, children: &'b [VNode<'b>]) -> bool {
+        // Most lists are either entirely unkeyed or a single keyed node, which don't need a set to check
+        if children.iter().all(|child| child.key.is_none()) {
+            return false;
+        }
+        if children.len() == 1 {
+            return true;
+        }
+
+        let mut unique = true;
+        for child in children {
+            let key = match child.key {
+                Some(key) => key,
+                None => {
+                    let template = child.template.get().name;
+                    if self.warned_unkeyed.insert(template) {
+                        log::warn!(
+                            "Found a list that mixes keyed and unkeyed siblings in the template {:?}. Either every sibling needs a key or none of them should have one, so this list will be diffed by position instead.",
+                            template
+                        );
+                    }
+                    unique = false;
+                    break;
+                }
+            };
+
+            // safety: the scratch set is always cleared before we return, so the key never outlives this call
+            let scratch_key = unsafe { std::mem::transmute::<&str, &'static str>(key) };
+            if !self.key_scratch.insert(scratch_key) {
+                if !self.warned_keys.contains(key) {
+                    log::warn!(
+                        "Found the key {:?} on more than one sibling. Keys must be unique, so this list will be diffed by position instead.",
+                        key
+                    );
+                    self.warned_keys.insert(key.to_string());
+                }
+                unique = false;
+                break;
+            }
+        }
+
+        self.key_scratch.clear();
+        unique
+    }
+
     // Diff children that are not keyed.
     //
     // The parent must be on the top of the change list stack when entering this
@@ -376,28 +441,6 @@ impl<'b> VirtualDom {
     //
     // The stack is empty upon entry.
     fn diff_keyed_children(&mut self, old: &'b [VNode<'b>], new: &'b [VNode<'b>]) {
-        if cfg!(debug_assertions) {
-            let mut keys = rustc_hash::FxHashSet::default();
-            let mut assert_unique_keys = |children: &'b [VNode<'b>]| {
-                keys.clear();
-                for child in children {
-                    let key = child.key;
-                    debug_assert!(
-                        key.is_some(),
-                        "if any sibling is keyed, all siblings must be keyed"
-                    );
-                    keys.insert(key);
-                }
-                debug_assert_eq!(
-                    children.len(),
-                    keys.len(),
-                    "keyed siblings must each have a unique key"
-                );
-            };
-            assert_unique_keys(old);
-            assert_unique_keys(new);
-        }
-
         // First up, we diff all the nodes with the same key at the beginning of the
         // children.
         //
@@ -723,7 +766,13 @@ impl<'b> VirtualDom {
                         let scope = comp.scope.get().unwrap();
                         match unsafe { self.scopes[scope].root_node().extend_lifetime_ref() } {
                             RenderReturn::Ready(node) => self.push_all_real_nodes(node),
-                            RenderReturn::Aborted(_node) => todo!(),
+                            // Components that rendered nothing still have a placeholder in the dom
+                            RenderReturn::Aborted(placeholder) => {
+                                self.mutations.push(Mutation::PushRoot {
+                                    id: placeholder.id.get().unwrap(),
+                                });
+                                1
+                            }
                             _ => todo!(),
                         }
                     }
@@ -937,6 +986,25 @@ impl<'b> VirtualDom {
         self.drop_scope(scope, false);
     }
 
+    /// Swap out the fallback of a suspense boundary for the tree on top of the stack, and unmount the fallback
+    pub(crate) fn replace_fallback(&mut self, fallback: ScopeId, m: usize) {
+        match unsafe { self.scopes[fallback].root_node().extend_lifetime_ref() } {
+            RenderReturn::Ready(t) => {
+                let id = self.find_first_element(t);
+                self.mutations.push(Mutation::InsertBefore { id, m });
+                self.remove_node(t, true);
+            }
+            RenderReturn::Aborted(placeholder) => {
+                let id = placeholder.id.get().unwrap();
+                self.mutations.push(Mutation::InsertBefore { id, m });
+                self.remove_placeholder(placeholder, true);
+            }
+            _ => todo!(),
+        }
+
+        self.drop_scope(fallback, false);
+    }
+
     fn find_first_element(&self, node: &'b VNode<'b>) -> ElementId {
         match node.dynamic_root(0) {
             None => node.root_ids.get(0).unwrap(),
@@ -947,6 +1015,7 @@ impl<'b> VirtualDom {
                 let scope = comp.scope.get().unwrap();
                 match unsafe { self.scopes[scope].root_node().extend_lifetime_ref() } {
                     RenderReturn::Ready(t) => self.find_first_element(t),
+                    RenderReturn::Aborted(placeholder) => placeholder.id.get().unwrap(),
                     _ => todo!("cannot handle nonstandard nodes"),
                 }
             }
@@ -963,6 +1032,7 @@ impl<'b> VirtualDom {
                 let scope = comp.scope.get().unwrap();
                 match unsafe { self.scopes[scope].root_node().extend_lifetime_ref() } {
                     RenderReturn::Ready(t) => self.find_last_element(t),
+                    RenderReturn::Aborted(placeholder) => placeholder.id.get().unwrap(),
                     _ => todo!("cannot handle nonstandard nodes"),
                 }
             }
@@ -1,14 +1,18 @@
 use crate::{ScopeId, ScopeState};
 use std::{
     any::{Any, TypeId},
-    cell::RefCell,
-    fmt::Debug,
+    cell::{Ref, RefCell},
+    fmt::{Debug, Display},
+    sync::Arc,
 };
 
 /// A boundary that will capture any errors from child components
 pub struct ErrorBoundary {
     error: RefCell<Option<CapturedError>>,
+    panicked: RefCell<Option<ComponentPanicked>>,
     _id: ScopeId,
+    // Re-renders the scope that provided the boundary so it can show a fallback
+    update: Option<Arc<dyn Fn() + Send + Sync>>,
 }
 
 /// An instance of an error captured by a descendant component.
@@ -33,19 +37,98 @@ impl CapturedError {
 }
 
 impl ErrorBoundary {
+    /// Create a boundary that only stores the errors it catches
     pub fn new(id: ScopeId) -> Self {
         Self {
             error: RefCell::new(None),
+            panicked: RefCell::new(None),
             _id: id,
+            update: None,
+        }
+    }
+
+    /// Create a boundary for a component that re-renders it whenever an error is caught
+    pub(crate) fn for_scope(cx: &ScopeState) -> Self {
+        Self {
+            update: Some(cx.schedule_update()),
+            ..Self::new(cx.scope_id())
         }
     }
 
     /// Push an error into this Error Boundary
     pub fn insert_error(&self, scope: ScopeId, error: Box<dyn Debug + 'static>) {
         self.error.replace(Some(CapturedError { error, scope }));
+        self.panicked.take();
+
+        if let Some(update) = &self.update {
+            update();
+        }
+    }
+
+    /// The last error this Error Boundary caught, if it hasn't been cleared
+    pub fn error(&self) -> Option<Ref<'_, CapturedError>> {
+        Ref::filter_map(self.error.borrow(), Option::as_ref).ok()
+    }
+
+    /// Forget the error this Error Boundary caught, so the component that provided it can render its children again
+    pub fn clear(&self) {
+        let had_error = self.error.take().is_some();
+        self.panicked.take();
+
+        if let (true, Some(update)) = (had_error, &self.update) {
+            update();
+        }
+    }
+
+    /// Push a panic from a component into this Error Boundary
+    pub(crate) fn insert_panic(&self, panicked: ComponentPanicked) {
+        self.insert_error(panicked.scope, Box::new(panicked.clone()));
+        self.panicked.replace(Some(panicked));
+    }
+
+    /// Take the panic out of this Error Boundary if it was thrown by the given scope
+    pub(crate) fn take_panic(&self, scope: ScopeId) -> Option<ComponentPanicked> {
+        let mut panicked = self.panicked.borrow_mut();
+        if panicked.as_ref()?.scope != scope {
+            return None;
+        }
+        self.error.take();
+        panicked.take()
+    }
+}
+
+/// The error thrown to the nearest Error Boundary when a component panics while rendering
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentPanicked {
+    /// The scope of the component that panicked
+    pub scope: ScopeId,
+
+    /// The message the component panicked with, if it was a string
+    pub message: String,
+}
+
+impl ComponentPanicked {
+    pub(crate) fn new(scope: ScopeId, payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&'static str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => String::from("Box<dyn Any>"),
+            },
+        };
+
+        Self { scope, message }
     }
 }
 
+impl Display for ComponentPanicked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "component {:?} panicked: {}", self.scope, self.message)
+    }
+}
+
+impl std::error::Error for ComponentPanicked {}
+
 /// A trait to allow results to be thrown upwards to the nearest Error Boundary
 ///
 /// The canonical way of using this trait is to throw results from hooks, aborting rendering
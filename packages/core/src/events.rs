@@ -106,8 +106,6 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Event<T> {
     }
 }
 
-#[doc(hidden)]
-
 /// The callback type generated by the `rsx!` macro when an `on` field is specified for components.
 ///
 /// This makes it possible to pass `move |evt| {}` style closures into components as property fields.
@@ -134,8 +132,20 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Event<T> {
 /// }
 ///
 /// ```
+///
+/// Cloning an `EventHandler` is cheap: all clones share the same underlying callback, so a handler can be passed
+/// further down the tree or stored in several places without re-allocating the closure. The default handler is a
+/// no-op, making it easy to accept optional callbacks in props.
 pub struct EventHandler<'bump, T = ()> {
-    pub(super) callback: RefCell<Option<ExternalListenerCallback<'bump, T>>>,
+    pub(super) callback: Rc<RefCell<Option<ExternalListenerCallback<'bump, T>>>>,
+}
+
+impl<T> Clone for EventHandler<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            callback: self.callback.clone(),
+        }
+    }
 }
 
 impl<T> Default for EventHandler<'_, T> {
@@ -160,7 +170,8 @@ impl<T> EventHandler<'_, T> {
 
     /// Forcibly drop the internal handler callback, releasing memory
     ///
-    /// This will force any future calls to "call" to not doing anything
+    /// This will force any future calls to "call" to not doing anything. Since clones share the callback, this
+    /// releases the callback for every clone of this handler.
     pub fn release(&self) {
         self.callback.replace(None);
     }
@@ -21,6 +21,8 @@ mod virtual_dom;
 
 pub(crate) mod innerlude {
     pub use crate::arena::*;
+    #[cfg(feature = "frame-stats")]
+    pub use crate::bump_frame::FrameStats;
     pub use crate::dirty_scope::*;
     pub use crate::error_boundary::*;
     pub use crate::events::*;
@@ -71,12 +73,15 @@ pub(crate) mod innerlude {
 
 pub use crate::innerlude::{
     fc_to_builder, AnyValue, Attribute, AttributeValue, BorrowedAttributeValue, CapturedError,
-    Component, DynamicNode, Element, ElementId, Event, Fragment, IntoDynNode, LazyNodes, Mutation,
-    Mutations, Properties, RenderReturn, Scope, ScopeId, ScopeState, Scoped, SuspenseContext,
-    TaskId, Template, TemplateAttribute, TemplateNode, VComponent, VNode, VPlaceholder, VText,
-    VirtualDom,
+    Component, ComponentPanicked, DynamicNode, Element, ElementId, ErrorBoundary, Event, Fragment,
+    IntoDynNode, LazyNodes, Mutation, Mutations, Properties, RenderReturn, Scope, ScopeId,
+    ScopeState, Scoped, SuspenseContext, TaskId, Template, TemplateAttribute, TemplateNode,
+    VComponent, VNode, VPlaceholder, VText, VirtualDom,
 };
 
+#[cfg(feature = "frame-stats")]
+pub use crate::innerlude::FrameStats;
+
 /// The purpose of this module is to alleviate imports of many common types
 ///
 /// This includes types like [`Scope`], [`Element`], and [`Component`].
@@ -592,7 +592,7 @@ impl Debug for BorrowedAttributeValue<'_> {
 impl PartialEq for BorrowedAttributeValue<'_> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::Text(l0), Self::Text(r0)) => l0 == r0,
+            (Self::Text(l0), Self::Text(r0)) => str_eq(l0, r0),
             (Self::Float(l0), Self::Float(r0)) => l0 == r0,
             (Self::Int(l0), Self::Int(r0)) => l0 == r0,
             (Self::Bool(l0), Self::Bool(r0)) => l0 == r0,
@@ -602,6 +602,15 @@ impl PartialEq for BorrowedAttributeValue<'_> {
     }
 }
 
+/// Compare two strings, skipping the byte comparison if both point to the same memory.
+///
+/// Text that isn't formatted at runtime is borrowed instead of being copied into the bump arena, so unchanged static
+/// text is equal by pointer between renders.
+#[inline]
+pub(crate) fn str_eq(left: &str, right: &str) -> bool {
+    std::ptr::eq(left, right) || left == right
+}
+
 #[cfg(feature = "serialize")]
 fn serialize_any_value<S>(_: &std::cell::Ref<'_, dyn AnyValue>, _: S) -> Result<S::Ok, S::Error>
 where
@@ -635,7 +644,7 @@ impl<'a> std::fmt::Debug for AttributeValue<'a> {
 impl<'a> PartialEq for AttributeValue<'a> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::Text(l0), Self::Text(r0)) => l0 == r0,
+            (Self::Text(l0), Self::Text(r0)) => str_eq(l0, r0),
             (Self::Float(l0), Self::Float(r0)) => l0 == r0,
             (Self::Int(l0), Self::Int(r0)) => l0 == r0,
             (Self::Bool(l0), Self::Bool(r0)) => l0 == r0,
@@ -873,6 +882,11 @@ impl<'a> IntoAttributeValue<'a> for bool {
 
 impl<'a> IntoAttributeValue<'a> for Arguments<'_> {
     fn into_value(self, bump: &'a Bump) -> AttributeValue<'a> {
+        // Arguments without any interpolation can be borrowed directly instead of being copied into the bump
+        if let Some(static_str) = self.as_str() {
+            return AttributeValue::Text(static_str);
+        }
+
         use bumpalo::core_alloc::fmt::Write;
         let mut str_buf = bumpalo::collections::String::new_in(bump);
         str_buf.write_fmt(self).unwrap();
@@ -16,6 +16,9 @@ pub(crate) enum SchedulerMsg {
     /// Immediate updates from Components that mark them as dirty
     Immediate(ScopeId),
 
+    /// Mark a component and every component below it as dirty
+    Subtree(ScopeId),
+
     /// A task has woken and needs to be progressed
     TaskNotified(TaskId),
 
@@ -2,7 +2,7 @@ use futures_util::task::ArcWake;
 
 use super::SchedulerMsg;
 use crate::ElementId;
-use crate::{innerlude::Mutations, Element, ScopeId};
+use crate::{innerlude::Mutations, Component, Element, ScopeId};
 use std::future::Future;
 use std::sync::Arc;
 use std::task::Waker;
@@ -22,6 +22,10 @@ pub struct SuspenseContext {
     pub(crate) mutations: RefCell<Mutations<'static>>,
     pub(crate) placeholder: Cell<Option<ElementId>>,
     pub(crate) created_on_stack: Cell<usize>,
+    pub(crate) fallback: Option<Component>,
+
+    // The scope rendering the fallback while the tree is suspended
+    pub(crate) fallback_scope: Cell<Option<ScopeId>>,
 }
 
 impl SuspenseContext {
@@ -33,6 +37,34 @@ impl SuspenseContext {
             mutations: RefCell::new(Mutations::default()),
             placeholder: Cell::new(None),
             created_on_stack: Cell::new(0),
+            fallback: None,
+            fallback_scope: Cell::new(None),
+        }
+    }
+
+    /// Create a new boundary for suspense that renders a fallback component until everything below it is ready.
+    ///
+    /// The nodes of the component that provides the boundary are held back while its tree is suspended, so the
+    /// fallback is mounted where that component goes instead. Once the last suspended component resolves, the
+    /// fallback is unmounted and replaced with the whole tree.
+    ///
+    /// ```rust, ignore
+    /// fn Loading(cx: Scope) -> Element {
+    ///     cx.use_hook(|| {
+    ///         cx.provide_context(Rc::new(SuspenseContext::with_fallback(cx.scope_id(), Spinner)));
+    ///     });
+    ///
+    ///     cx.render(rsx!(AsyncProfile {}))
+    /// }
+    ///
+    /// fn Spinner(cx: Scope) -> Element {
+    ///     cx.render(rsx!( div { class: "spinner", "Loading..." } ))
+    /// }
+    /// ```
+    pub fn with_fallback(id: ScopeId, fallback: Component) -> Self {
+        Self {
+            fallback: Some(fallback),
+            ..Self::new(id)
         }
     }
 }
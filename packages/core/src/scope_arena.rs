@@ -44,6 +44,9 @@ impl VirtualDom {
             shared_contexts: Default::default(),
             borrowed_props: Default::default(),
             attributes_to_drop: Default::default(),
+            offscreen: Default::default(),
+            render_deferred: Default::default(),
+            deferred_props: Default::default(),
         })
     }
 
@@ -59,9 +62,16 @@ impl VirtualDom {
         // Remove all the outdated listeners
         self.ensure_drop_safety(scope_id);
 
-        let mut new_nodes = unsafe {
-            self.scopes[scope_id].previous_frame().bump_mut().reset();
+        unsafe { self.scopes[scope_id].previous_frame().bump_mut().reset() };
+
+        // Resetting the frame keeps its last chunk of memory around for this render
+        #[cfg(feature = "frame-stats")]
+        let kept = self.scopes[scope_id]
+            .previous_frame()
+            .bump()
+            .allocated_bytes();
 
+        let mut new_nodes = unsafe {
             let scope = &self.scopes[scope_id];
 
             scope.hook_idx.set(0);
@@ -136,6 +146,18 @@ impl VirtualDom {
         let allocated = &*frame.bump().alloc(new_nodes);
         frame.node.set(allocated);
 
+        // Count the memory this render used. The first render into each frame uses the memory it was created with
+        #[cfg(feature = "frame-stats")]
+        {
+            let total = frame.bump().allocated_bytes();
+            if scope.render_cnt.get() < 2 {
+                self.frame_stats.allocated += total;
+            } else {
+                self.frame_stats.reused += kept;
+                self.frame_stats.allocated += total - kept;
+            }
+        }
+
         // And move the render generation forward by one
         scope.render_cnt.set(scope.render_cnt.get() + 1);
 
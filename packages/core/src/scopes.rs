@@ -184,6 +184,10 @@ pub struct ScopeState {
 
     pub(crate) props: Option<Box<dyn AnyProps<'static>>>,
     pub(crate) placeholder: Cell<Option<ElementId>>,
+
+    pub(crate) offscreen: Cell<bool>,
+    pub(crate) render_deferred: Cell<bool>,
+    pub(crate) deferred_props: Option<Box<dyn AnyProps<'static>>>,
 }
 
 impl<'src> ScopeState {
@@ -292,6 +296,39 @@ impl<'src> ScopeState {
         self.parent.map(|p| unsafe { &*p }.id)
     }
 
+    /// Get the IDs of every [`Scope`] above this one, starting with the parent and ending with the root.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// fn Outlet(cx: Scope) -> Element {
+    ///     // The outlet is rendered inside of a layout, which is rendered by the app
+    ///     let depth = cx.ancestors().count();
+    ///
+    ///     cx.render(rsx!( div { "This outlet is {depth} components deep" } ))
+    /// }
+    /// ```
+    pub fn ancestors(&self) -> impl Iterator<Item = ScopeId> + '_ {
+        self.parent_scopes().map(|scope| scope.id)
+    }
+
+    /// Get the names of the components above this one, in the same order as [`ScopeState::ancestors`].
+    pub fn ancestor_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.parent_scopes().map(|scope| scope.name)
+    }
+
+    fn parent_scopes(&self) -> impl Iterator<Item = &ScopeState> + '_ {
+        // safety: the pointer to our parent is *always* valid thanks to the bump arena
+        std::iter::successors(self.parent.map(|p| unsafe { &*p }), |scope| {
+            scope.parent.map(|p| unsafe { &*p })
+        })
+    }
+
+    /// Get the ID of the [`Scope`] at the root of the [`crate::VirtualDom`] this scope lives in.
+    pub fn root_scope(&self) -> ScopeId {
+        self.ancestors().last().unwrap_or(self.id)
+    }
+
     /// Get the ID of this Scope within this Dioxus [`crate::VirtualDom`].
     ///
     /// This ID is not unique across Dioxus [`crate::VirtualDom`]s or across time. IDs will be reused when components are unmounted.
@@ -334,6 +371,36 @@ impl<'src> ScopeState {
         self.needs_update_any(self.scope_id());
     }
 
+    /// Schedule a render for this scope and every scope below it, even the ones with memoized props.
+    ///
+    /// This is useful for state that lives outside of props and contexts, like the current url of a router.
+    pub fn needs_update_subtree(&self) {
+        self.tasks
+            .sender
+            .unbounded_send(SchedulerMsg::Subtree(self.scope_id()))
+            .expect("Scheduler to exist if scope exists");
+    }
+
+    /// Mark this scope as offscreen or visible.
+    ///
+    /// While a scope is offscreen, it keeps its last rendered output and skips re-renders, whether they come from its
+    /// own state changing or from its parent passing it new props. Components with borrowed props can't be gated and
+    /// are always re-rendered with their parent.
+    ///
+    /// Once the scope becomes visible again, any update that was skipped while it was offscreen is rendered.
+    pub fn set_offscreen(&self, offscreen: bool) {
+        self.offscreen.set(offscreen);
+
+        if !offscreen && self.render_deferred.get() {
+            self.needs_update();
+        }
+    }
+
+    /// Check if this scope is currently marked as offscreen
+    pub fn is_offscreen(&self) -> bool {
+        self.offscreen.get()
+    }
+
     /// Get the [`ScopeId`] of a mounted component.
     ///
     /// `ScopeId` is not unique for the lifetime of the [`crate::VirtualDom`] - a [`ScopeId`] will be reused if a component is unmounted.
@@ -574,7 +641,7 @@ impl<'src> ScopeState {
     pub fn event_handler<T>(&'src self, f: impl FnMut(T) + 'src) -> EventHandler<'src, T> {
         let handler: &mut dyn FnMut(T) = self.bump().alloc(f);
         let caller = unsafe { BumpBox::from_raw(handler as *mut dyn FnMut(T)) };
-        let callback = RefCell::new(Some(caller));
+        let callback = Rc::new(RefCell::new(Some(caller)));
         EventHandler { callback }
     }
 
@@ -627,6 +694,31 @@ impl<'src> ScopeState {
         None
     }
 
+    /// Catch the errors thrown by the components below this one, including components that panic while rendering.
+    ///
+    /// Whenever a descendant throws, the error is stored in the returned boundary and this component is re-rendered,
+    /// so it can render a fallback instead of its children. Errors thrown by this component itself are caught by the
+    /// boundary too, so it shouldn't throw while rendering the fallback. Clearing the boundary renders the children
+    /// again.
+    ///
+    /// ```rust, ignore
+    /// fn Boundary(cx: Scope) -> Element {
+    ///     let boundary = cx.use_error_boundary();
+    ///
+    ///     if let Some(error) = boundary.error() {
+    ///         return cx.render(rsx! {
+    ///             "Something went wrong: {error.error:?}"
+    ///             button { onclick: move |_| boundary.clear(), "Try again" }
+    ///         });
+    ///     }
+    ///
+    ///     cx.render(rsx!(Dashboard {}))
+    /// }
+    /// ```
+    pub fn use_error_boundary(&self) -> &Rc<ErrorBoundary> {
+        self.use_hook(|| self.provide_context(Rc::new(ErrorBoundary::for_scope(self))))
+    }
+
     /// Store a value between renders. The foundational hook for all other hooks.
     ///
     /// Accepts an `initializer` closure, which is run on the first use of the hook (typically the initial render). The return value of this closure is stored for the lifetime of the component, and a mutable reference to it is provided on every render as the return value of `use_hook`.
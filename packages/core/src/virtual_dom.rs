@@ -3,18 +3,20 @@
 //! This module provides the primary mechanics to create a hook-based, concurrent VDOM for Rust.
 
 use crate::{
-    any_props::VProps,
-    arena::{ElementId, ElementRef},
-    innerlude::{DirtyScope, ErrorBoundary, Mutations, Scheduler, SchedulerMsg, ScopeSlab},
+    any_props::{AnyProps, VProps},
+    arena::{ElementId, ElementPath, ElementRef},
+    innerlude::{
+        ComponentPanicked, DirtyScope, ErrorBoundary, Mutations, Scheduler, SchedulerMsg, ScopeSlab,
+    },
     mutations::Mutation,
     nodes::RenderReturn,
-    nodes::{Template, TemplateId},
+    nodes::{DynamicNode, Template, TemplateId, VNode},
     scheduler::SuspenseId,
     scopes::{ScopeId, ScopeState},
     AttributeValue, Element, Event, Scope, SuspenseContext,
 };
 use futures_util::{pin_mut, StreamExt};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use slab::Slab;
 use std::{any::Any, borrow::BorrowMut, cell::Cell, collections::BTreeSet, future::Future, rc::Rc};
 
@@ -155,6 +157,8 @@ use std::{any::Any, borrow::BorrowMut, cell::Cell, collections::BTreeSet, future
 /// let edits = dom.render_with_deadline(deadline).await;
 /// ```
 ///
+/// Or, to wait for every suspended component no matter how long it takes, use [`VirtualDom::progress_completely`].
+///
 /// ## Use with streaming
 ///
 /// If not all rendering is done by the deadline, it might be worthwhile to stream the rest later. To do this, we
@@ -195,6 +199,19 @@ pub struct VirtualDom {
     pub(crate) rx: futures_channel::mpsc::UnboundedReceiver<SchedulerMsg>,
 
     pub(crate) mutations: Mutations<'static>,
+
+    // The render function of the root component, kept around so the root props can be replaced
+    pub(crate) root_render: Box<dyn Any>,
+
+    // Reused to check the keys of every list we diff. Always empty between diffs
+    pub(crate) key_scratch: FxHashSet<&'static str>,
+
+    // The templates and keys we've already warned about, so a list that's rendered over and over only warns once
+    pub(crate) warned_unkeyed: FxHashSet<&'static str>,
+    pub(crate) warned_keys: FxHashSet<String>,
+
+    #[cfg(feature = "frame-stats")]
+    pub(crate) frame_stats: crate::FrameStats,
 }
 
 impl VirtualDom {
@@ -202,7 +219,8 @@ impl VirtualDom {
     ///
     /// # Description
     ///
-    /// Later, the props can be updated by calling "update" with a new set of props, causing a set of re-renders.
+    /// Later, the props can be updated by calling [`VirtualDom::update_props`] with a new set of props, causing a set of
+    /// re-renders.
     ///
     /// This is useful when a component tree can be driven by external state (IE SSR) but it would be too expensive
     /// to toss out the entire tree.
@@ -226,7 +244,8 @@ impl VirtualDom {
     ///
     /// # Description
     ///
-    /// Later, the props can be updated by calling "update" with a new set of props, causing a set of re-renders.
+    /// Later, the props can be updated by calling [`VirtualDom::update_props`] with a new set of props, causing a set of
+    /// re-renders.
     ///
     /// This is useful when a component tree can be driven by external state (IE SSR) but it would be too expensive
     /// to toss out the entire tree.
@@ -265,6 +284,12 @@ impl VirtualDom {
             collected_leaves: Vec::new(),
             finished_fibers: Vec::new(),
             mutations: Mutations::default(),
+            root_render: Box::new(root),
+            key_scratch: Default::default(),
+            warned_unkeyed: Default::default(),
+            warned_keys: Default::default(),
+            #[cfg(feature = "frame-stats")]
+            frame_stats: Default::default(),
         };
 
         let root = dom.new_scope(
@@ -301,6 +326,16 @@ impl VirtualDom {
         self.scopes.get(ScopeId(0)).unwrap()
     }
 
+    /// Get how many bytes the bump frames of every scope have allocated so far, and how many were reused from an
+    /// earlier render.
+    ///
+    /// Each scope renders into one of two frames, and the frame from two renders ago is reset before it's rendered
+    /// into again. Once the frames have grown to fit a scope, re-rendering it should only add to `reused`.
+    #[cfg(feature = "frame-stats")]
+    pub fn frame_stats(&self) -> crate::FrameStats {
+        self.frame_stats
+    }
+
     /// Build the virtualdom with a global context inserted into the base scope
     ///
     /// This is useful for what is essentially dependency injection when building the app
@@ -309,6 +344,55 @@ impl VirtualDom {
         self
     }
 
+    /// Replace the props of the root component, re-rendering it the next time the VirtualDom does work.
+    ///
+    /// This is useful when the tree is driven by external state, like a server rendering the same page for different
+    /// requests. If the new props are equal to the current ones, nothing is re-rendered.
+    ///
+    /// ```rust, ignore
+    /// let mut dom = VirtualDom::new_with_props(Example, SomeProps { name: "jane" });
+    /// let _ = dom.rebuild();
+    ///
+    /// dom.update_props(SomeProps { name: "jack" });
+    /// let mutations = dom.render_immediate();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the props are not the same type as the ones the VirtualDom was created with.
+    pub fn update_props<P: PartialEq + 'static>(&mut self, props: P) {
+        let root = *self
+            .root_render
+            .downcast_ref::<fn(Scope<P>) -> Element>()
+            .expect("The props must have the same type the VirtualDom was created with");
+
+        let scope = &mut self.scopes[ScopeId(0)];
+        let current = scope
+            .deferred_props
+            .as_ref()
+            .or(scope.props.as_ref())
+            .unwrap();
+
+        // Safety: the render function has the same type, so the props of the root are a P
+        let current = unsafe { &*(current.props_ptr() as *const P) };
+        if *current == props {
+            return;
+        }
+
+        let props: Box<dyn AnyProps<'static>> =
+            Box::new(VProps::new(root, |_, _| unreachable!(), props));
+
+        // Nothing has rendered with the current props yet, so they can be swapped out right away. Otherwise, the new
+        // props are picked up when the root is re-rendered, just like the props of a scope that was offscreen.
+        if scope.render_cnt.get() == 0 {
+            scope.props = Some(props);
+            scope.deferred_props = None;
+        } else {
+            scope.deferred_props = Some(props);
+            self.mark_dirty(ScopeId(0));
+        }
+    }
+
     /// Manually mark a scope as requiring a re-render
     ///
     /// Whenever the VirtualDom "works", it will re-render this scope
@@ -319,6 +403,21 @@ impl VirtualDom {
         }
     }
 
+    /// Mark a scope and every scope below it as dirty, even if their props haven't changed
+    pub fn mark_subtree_dirty(&mut self, id: ScopeId) {
+        let subtree = self
+            .scopes
+            .iter()
+            .filter(|scope| scope.id == id || scope.ancestors().any(|ancestor| ancestor == id))
+            .map(|scope| DirtyScope {
+                height: scope.height,
+                id: scope.id,
+            })
+            .collect::<Vec<_>>();
+
+        self.dirty_scopes.extend(subtree);
+    }
+
     /// Determine whether or not a scope is currently in a suspended state
     ///
     /// This does not mean the scope is waiting on its own futures, just that the tree that the scope exists in is
@@ -362,7 +461,8 @@ impl VirtualDom {
         With the target path, we try and move up to the parent until there is no parent.
         Due to how bubbling works, we call the listeners before walking to the parent.
 
-        If we wanted to do capturing, then we would accumulate all the listeners and call them in reverse order.
+        Capture listeners (like `onclickcapture`) are collected the same way, but from the outermost template in, and are
+        called before any of the bubbling listeners.
         ----------------------
 
         For a visual demonstration, here we present a tree on the left and whether or not a listener is collected on the
@@ -375,35 +475,76 @@ impl VirtualDom {
         | | |       <-- no, broke early
         |           <-- no, broke early
         */
-        let mut parent_path = self.elements.get(element.0);
-        let mut listeners = vec![];
-
         // We will clone this later. The data itself is wrapped in RC to be used in callbacks if required
         let uievent = Event {
             propagates: Rc::new(Cell::new(bubbles)),
             data,
         };
 
+        // Every template from the one holding the target out to the root, along with the path to the target inside it
+        let mut templates = vec![];
+        let mut parent_path = self
+            .element_template(element)
+            .map(|(el_ref, template)| (el_ref.scope, template, el_ref.path));
+
+        while let Some((scope, template, target_path)) = parent_path {
+            templates.push((template, target_path));
+
+            parent_path = match template.parent.and_then(|id| self.element_template(id)) {
+                Some((el_ref, parent)) => Some((el_ref.scope, parent, el_ref.path)),
+                None => self
+                    .find_parent_template(scope, template)
+                    .map(|(scope, parent, path)| (scope, parent, ElementPath::Deep(path))),
+            };
+        }
+
+        let mut listeners = vec![];
+
+        // Capture listeners are called first, from the outermost ancestor in towards the target. They see the event
+        // even if it doesn't bubble, since the capture phase always runs through every ancestor.
+        for (template, target_path) in templates.iter().rev() {
+            let node_template = template.template.get();
+
+            // Attributes are stored in the order they appear in the template, so ancestors come before their children
+            for (idx, attr) in template.dynamic_attrs.iter().enumerate() {
+                let this_path = node_template.attr_paths[idx];
+                let captures =
+                    attr.name.trim_start_matches("on").strip_suffix("capture") == Some(name);
+
+                if captures && target_path.is_decendant(&this_path) {
+                    listeners.push(&attr.value);
+                }
+            }
+        }
+
+        // Stopping propagation in the capture phase stops the event from reaching the target, so it always propagates
+        // until we get there
+        uievent.propagates.set(true);
+        if !call_listeners(listeners.drain(..), &uievent) {
+            return;
+        }
+        uievent.propagates.set(bubbles);
+
         // Loop through each dynamic attribute in this template before moving up to the template's parent.
-        while let Some(el_ref) = parent_path {
-            // safety: we maintain references of all vnodes in the element slab
-            let template = unsafe { el_ref.template.unwrap().as_ref() };
+        for (template, target_path) in templates {
             let node_template = template.template.get();
-            let target_path = el_ref.path;
 
             for (idx, attr) in template.dynamic_attrs.iter().enumerate() {
                 let this_path = node_template.attr_paths[idx];
 
                 // Remove the "on" prefix if it exists, TODO, we should remove this and settle on one
-                if attr.name.trim_start_matches("on") == name
-                    && target_path.is_ascendant(&this_path)
-                {
-                    listeners.push(&attr.value);
+                if attr.name.trim_start_matches("on") != name {
+                    continue;
+                }
 
-                    // Break if the event doesn't bubble anyways
-                    if !bubbles {
-                        break;
-                    }
+                // Events that don't bubble are only delivered to the target itself
+                if !bubbles && target_path != this_path {
+                    continue;
+                }
+
+                // Collect listeners on the target and all of its ancestors in this template
+                if target_path.is_decendant(&this_path) {
+                    listeners.push(&attr.value);
 
                     // Break if this is the exact target element.
                     // This means we won't call two listeners with the same name on the same element. This should be
@@ -414,22 +555,76 @@ impl VirtualDom {
                 }
             }
 
-            // Now that we've accumulated all the parent attributes for the target element, call them in reverse order
+            // Attributes are stored in the order they appear in the template, so ancestors come before their children.
+            // Call them in reverse order so the innermost listener is called first, just like the DOM does.
             // We check the bubble state between each call to see if the event has been stopped from bubbling
-            for listener in listeners.drain(..).rev() {
-                if let AttributeValue::Listener(listener) = listener {
-                    if let Some(cb) = listener.borrow_mut().as_deref_mut() {
-                        cb(uievent.clone());
-                    }
+            if !call_listeners(listeners.drain(..).rev(), &uievent) {
+                return;
+            }
+
+            if !bubbles {
+                return;
+            }
+        }
+    }
+
+    /// Find the template that contains the given template, along with the path to where it is mounted.
+    ///
+    /// Templates nested inside of fragments are found in the tree of the scope that rendered them, and the root of a
+    /// scope is found where its component is placed in the tree of its parent scope.
+    fn find_parent_template<'a>(
+        &'a self,
+        scope: ScopeId,
+        template: &VNode,
+    ) -> Option<(ScopeId, &'a VNode<'a>, &'static [u8])> {
+        fn find<'a>(
+            node: &'a VNode<'a>,
+            matches: &dyn Fn(&'a DynamicNode<'a>) -> bool,
+        ) -> Option<(&'a VNode<'a>, &'static [u8])> {
+            for (idx, dynamic) in node.dynamic_nodes.iter().enumerate() {
+                if matches(dynamic) {
+                    return Some((node, node.template.get().node_paths[idx]));
+                }
 
-                    if !uievent.propagates.get() {
-                        return;
+                if let DynamicNode::Fragment(children) = dynamic {
+                    if let Some(found) = children.iter().find_map(|child| find(child, matches)) {
+                        return Some(found);
                     }
                 }
             }
 
-            parent_path = template.parent.and_then(|id| self.elements.get(id.0));
+            None
+        }
+
+        fn root_of(scope: &ScopeState) -> Option<&VNode> {
+            match scope.try_root_node()? {
+                RenderReturn::Ready(node) => Some(node),
+                _ => None,
+            }
+        }
+
+        let scope_state = self.scopes.get(scope)?;
+        let root = root_of(scope_state)?;
+
+        // The template is the root of its scope, so look for the component in the parent scope
+        if std::ptr::eq(root, template) {
+            // safety: all parent pointers are valid thanks to the bump arena
+            let parent = unsafe { &*scope_state.parent? };
+            let (node, path) = find(
+                root_of(parent)?,
+                &|dynamic| matches!(dynamic, DynamicNode::Component(comp) if comp.scope.get() == Some(scope)),
+            )?;
+            return Some((parent.id, node, path));
         }
+
+        // Otherwise the template is somewhere inside a fragment in this scope
+        let (node, path) = find(root, &|dynamic| match dynamic {
+            DynamicNode::Fragment(children) => {
+                children.iter().any(|child| std::ptr::eq(child, template))
+            }
+            _ => false,
+        })?;
+        Some((scope, node, path))
     }
 
     /// Wait for the scheduler to have any work.
@@ -440,13 +635,24 @@ impl VirtualDom {
     ///
     /// This method is cancel-safe, so you're fine to discard the future in a select block.
     ///
-    /// This lets us poll async tasks and suspended trees during idle periods without blocking the main thread.
+    /// This lets us poll async tasks and suspended trees during idle periods without blocking the main thread, and lets
+    /// a renderer sleep until there is something to do instead of busy-polling the VirtualDom.
     ///
     /// # Example
     ///
     /// ```rust, ignore
-    /// let dom = VirtualDom::new(App);
-    /// let sender = dom.get_scheduler_channel();
+    /// let mut dom = VirtualDom::new(app);
+    /// let edits = dom.rebuild();
+    /// apply_edits(edits);
+    ///
+    /// loop {
+    ///     select! {
+    ///         _ = dom.wait_for_work() => {}
+    ///         evt = user_events.next() => dom.handle_event(evt.name, evt.data, evt.element, evt.bubbles),
+    ///     }
+    ///
+    ///     apply_edits(dom.render_immediate());
+    /// }
     /// ```
     pub async fn wait_for_work(&mut self) {
         let mut some_msg = None;
@@ -456,6 +662,7 @@ impl VirtualDom {
                 // If a bunch of messages are ready in a sequence, try to pop them off synchronously
                 Some(msg) => match msg {
                     SchedulerMsg::Immediate(id) => self.mark_dirty(id),
+                    SchedulerMsg::Subtree(id) => self.mark_subtree_dirty(id),
                     SchedulerMsg::TaskNotified(task) => self.handle_task_wakeup(task),
                     SchedulerMsg::SuspenseNotified(id) => self.handle_suspense_wakeup(id),
                 },
@@ -484,6 +691,7 @@ impl VirtualDom {
         while let Ok(Some(msg)) = self.rx.try_next() {
             match msg {
                 SchedulerMsg::Immediate(id) => self.mark_dirty(id),
+                SchedulerMsg::Subtree(id) => self.mark_subtree_dirty(id),
                 SchedulerMsg::TaskNotified(task) => self.handle_task_wakeup(task),
                 SchedulerMsg::SuspenseNotified(id) => self.handle_suspense_wakeup(id),
             }
@@ -525,11 +733,14 @@ impl VirtualDom {
     ///
     /// Any templates previously registered will remain.
     ///
+    /// This is the first call a renderer makes: apply the returned templates and edits to paint the initial tree, then
+    /// enter the event loop with [`VirtualDom::wait_for_work`].
+    ///
     /// # Example
     /// ```rust, ignore
     /// static App: Component = |cx|  cx.render(rsx!{ "hello world" });
     ///
-    /// let mut dom = VirtualDom::new();
+    /// let mut dom = VirtualDom::new(App);
     /// let edits = dom.rebuild();
     ///
     /// apply_edits(edits);
@@ -570,6 +781,59 @@ impl VirtualDom {
         }
     }
 
+    /// Render dirty scopes until `expired` returns true, leaving the rest of the work for the next call.
+    ///
+    /// A big update can take longer to render than a frame lasts. Renderers that want to stay responsive can render it
+    /// in slices: call this with a time budget, apply the mutations, give the event loop a chance to run and then call
+    /// it again. Scopes are rendered parents first, so the mutations of every slice leave the dom consistent. At least
+    /// one scope is rendered per call.
+    ///
+    /// [`VirtualDom::wait_for_work`] returns right away while there are dirty scopes left, so events that arrive
+    /// between two slices are handled before the next one.
+    ///
+    /// ```rust, ignore
+    /// let start = performance.now();
+    /// let mutations = dom.render_until(|| performance.now() - start > 5.0);
+    /// ```
+    pub fn render_until(&mut self, mut expired: impl FnMut() -> bool) -> Mutations {
+        self.process_events();
+
+        let mut rendered_any = false;
+        while let Some(dirty) = self.dirty_scopes.iter().next().cloned() {
+            if rendered_any && expired() {
+                return self.finalize();
+            }
+
+            self.dirty_scopes.remove(&dirty);
+            self.render_dirty_scope(dirty);
+            rendered_any = true;
+        }
+
+        // Pick up anything else that's ready, like suspense trees that finished
+        self.render_immediate()
+    }
+
+    /// Render whatever the VirtualDom has ready, like [`VirtualDom::render_immediate`], without letting a panicking
+    /// component unwind through the VirtualDom.
+    ///
+    /// A component that panics while rendering is replaced with a placeholder, and a [`ComponentPanicked`] error is
+    /// thrown to the nearest error boundary so one buggy component can't take down the rest of the app. If the root
+    /// component panics, there is nothing left to render, so the error is returned instead.
+    ///
+    /// Catching panics requires panics to unwind. When compiled with `panic = "abort"` (the default for wasm), a
+    /// panicking component aborts the program just like it would with [`VirtualDom::render_immediate`].
+    pub fn try_progress(&mut self) -> Result<Mutations, ComponentPanicked> {
+        let root_boundary = self.base_scope().has_context::<Rc<ErrorBoundary>>();
+
+        let mutations = self.render_immediate();
+
+        if let Some(panicked) = root_boundary.and_then(|b| b.take_panic(ScopeId(0))) {
+            return Err(panicked);
+        }
+
+        Ok(mutations)
+    }
+
     /// Render what you can given the timeline and then move on
     ///
     /// It's generally a good idea to put some sort of limit on the suspense process in case a future is having issues.
@@ -582,7 +846,7 @@ impl VirtualDom {
 
         loop {
             // first, unload any complete suspense trees
-            for finished_fiber in self.finished_fibers.drain(..) {
+            for finished_fiber in std::mem::take(&mut self.finished_fibers) {
                 let scope = &self.scopes[finished_fiber];
                 let context = scope.has_context::<Rc<SuspenseContext>>().unwrap();
 
@@ -594,57 +858,21 @@ impl VirtualDom {
                     .edits
                     .append(&mut context.mutations.borrow_mut().edits);
 
-                // TODO: count how many nodes are on the stack?
-                self.mutations.push(Mutation::ReplaceWith {
-                    id: context.placeholder.get().unwrap(),
-                    m: 1,
-                })
+                let created = context.created_on_stack.get();
+                match context.fallback_scope.take() {
+                    Some(fallback) => self.replace_fallback(fallback, created),
+                    None => self.mutations.push(Mutation::ReplaceWith {
+                        id: context.placeholder.get().unwrap(),
+                        m: created,
+                    }),
+                }
             }
 
             // Next, diff any dirty scopes
             // We choose not to poll the deadline since we complete pretty quickly anyways
             if let Some(dirty) = self.dirty_scopes.iter().next().cloned() {
                 self.dirty_scopes.remove(&dirty);
-
-                // If the scope doesn't exist for whatever reason, then we should skip it
-                if !self.scopes.contains(dirty.id) {
-                    continue;
-                }
-
-                // if the scope is currently suspended, then we should skip it, ignoring any tasks calling for an update
-                if self.is_scope_suspended(dirty.id) {
-                    continue;
-                }
-
-                // Save the current mutations length so we can split them into boundary
-                let mutations_to_this_point = self.mutations.edits.len();
-
-                // Run the scope and get the mutations
-                self.run_scope(dirty.id);
-                self.diff_scope(dirty.id);
-
-                // If suspended leaves are present, then we should find the boundary for this scope and attach things
-                // No placeholder necessary since this is a diff
-                if !self.collected_leaves.is_empty() {
-                    let mut boundary = self.scopes[dirty.id]
-                        .consume_context::<Rc<SuspenseContext>>()
-                        .unwrap();
-
-                    let boundary_mut = boundary.borrow_mut();
-
-                    // Attach mutations
-                    boundary_mut
-                        .mutations
-                        .borrow_mut()
-                        .edits
-                        .extend(self.mutations.edits.split_off(mutations_to_this_point));
-
-                    // Attach suspended leaves
-                    boundary
-                        .waiting_on
-                        .borrow_mut()
-                        .extend(self.collected_leaves.drain(..));
-                }
+                self.render_dirty_scope(dirty);
             }
 
             // If there's more work, then just continue, plenty of work to do
@@ -673,12 +901,107 @@ impl VirtualDom {
         }
     }
 
+    /// Render until every suspended subtree has resolved
+    ///
+    /// This is [`VirtualDom::render_with_deadline`] without a deadline: suspended components are awaited, and any
+    /// components they mount in turn are rendered too, until there is no work left. The edits from every round are
+    /// returned together.
+    ///
+    /// If a future never resolves, neither does this. Prefer [`VirtualDom::render_with_deadline`] when rendering
+    /// components you don't control.
+    ///
+    /// ```rust, ignore
+    /// let mut dom = VirtualDom::new(app);
+    /// _ = dom.rebuild();
+    ///
+    /// let edits = dom.progress_completely().await;
+    /// ```
+    pub async fn progress_completely(&mut self) -> Mutations {
+        self.render_with_deadline(std::future::pending()).await
+    }
+
+    /// Run and diff a scope that was taken out of the dirty scopes
+    fn render_dirty_scope(&mut self, dirty: DirtyScope) {
+        // If the scope doesn't exist for whatever reason, then we should skip it
+        if !self.scopes.contains(dirty.id) {
+            return;
+        }
+
+        // if the scope is currently suspended, then we should skip it, ignoring any tasks calling for an update
+        if self.is_scope_suspended(dirty.id) {
+            return;
+        }
+
+        // Offscreen scopes keep their last output until they are visible again
+        let scope = &mut self.scopes[dirty.id];
+        if scope.offscreen.get() {
+            scope.render_deferred.set(true);
+            return;
+        }
+
+        // Pick up any props the parent passed down while this scope was offscreen
+        scope.render_deferred.set(false);
+        if let Some(props) = scope.deferred_props.take() {
+            scope.props = Some(props);
+        }
+
+        // Save the current mutations length so we can split them into boundary
+        let mutations_to_this_point = self.mutations.edits.len();
+
+        // Run the scope and get the mutations
+        self.run_scope(dirty.id);
+        self.diff_scope(dirty.id);
+
+        // If suspended leaves are present, then we should find the boundary for this scope and attach things
+        // No placeholder necessary since this is a diff
+        if !self.collected_leaves.is_empty() {
+            let mut boundary = self.scopes[dirty.id]
+                .consume_context::<Rc<SuspenseContext>>()
+                .unwrap();
+
+            let boundary_mut = boundary.borrow_mut();
+
+            // Attach mutations
+            boundary_mut
+                .mutations
+                .borrow_mut()
+                .edits
+                .extend(self.mutations.edits.split_off(mutations_to_this_point));
+
+            // Attach suspended leaves
+            boundary
+                .waiting_on
+                .borrow_mut()
+                .extend(self.collected_leaves.drain(..));
+        }
+    }
+
     /// Swap the current mutations with a new
     fn finalize(&mut self) -> Mutations {
         std::mem::take(&mut self.mutations)
     }
 }
 
+/// Call each listener in order, returning false as soon as one of them stops the event from propagating
+fn call_listeners<'a, 'b>(
+    listeners: impl Iterator<Item = &'a AttributeValue<'b>>,
+    event: &Event<dyn Any>,
+) -> bool {
+    for listener in listeners {
+        if let AttributeValue::Listener(listener) = listener {
+            if let Some(cb) = listener.borrow_mut().as_deref_mut() {
+                cb(event.clone());
+            }
+
+            if !event.propagates.get() {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
 impl Drop for VirtualDom {
     fn drop(&mut self) {
         // Simply drop this scope which drops all of its children
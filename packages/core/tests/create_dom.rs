@@ -33,6 +33,33 @@ fn test_original_diff() {
     )
 }
 
+#[test]
+fn rebuild_hello_world() {
+    let mut dom = VirtualDom::new(|cx| cx.render(rsx!( div { "hello world" } )));
+
+    let mutations = dom.rebuild().santize();
+
+    // the static template is sent along with the edits that mount it
+    assert_eq!(mutations.templates.len(), 1);
+    assert_eq!(
+        mutations.templates[0].roots,
+        &[TemplateNode::Element {
+            tag: "div",
+            namespace: None,
+            attrs: &[],
+            children: &[TemplateNode::Text { text: "hello world" }],
+        }]
+    );
+
+    assert_eq!(
+        mutations.edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(1) },
+            AppendChildren { m: 1, id: ElementId(0) }
+        ]
+    )
+}
+
 #[test]
 fn create() {
     let mut dom = VirtualDom::new(|cx| {
@@ -356,3 +356,64 @@ fn no_common_keys() {
         ]
     );
 }
+
+/// Lists with duplicate keys can't be matched up by key, so they're diffed by position
+#[test]
+fn keyed_diffing_duplicate_keys_fall_back_to_position() {
+    let mut dom = VirtualDom::new(|cx| {
+        let order = match cx.generation() % 2 {
+            0 => &[0, 0, 1],
+            1 => &[1, 0, 0],
+            _ => unreachable!(),
+        };
+
+        cx.render(rsx!(order.iter().map(|i| rsx!(div { key: "{i}", "{i}" }))))
+    });
+
+    _ = dom.rebuild();
+
+    dom.mark_dirty(ScopeId(0));
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [
+            SetText { value: "1", id: ElementId(2,) },
+            SetText { value: "0", id: ElementId(6,) },
+        ]
+    );
+}
+
+/// Components that render nothing still take up a spot in the list, so they have to be movable like any other node
+#[test]
+fn keyed_diffing_moves_empty_components() {
+    #[inline_props]
+    fn Child(cx: Scope, show: bool) -> Element {
+        if !*show {
+            return None;
+        }
+        cx.render(rsx!(div {}))
+    }
+
+    let mut dom = VirtualDom::new(|cx| {
+        let order = match cx.generation() % 2 {
+            0 => &[0, 1, 2, 3, /**/ 4, 5, 6, /**/ 7, 8, 9],
+            1 => &[0, 1, 2, 3, /**/ 6, 4, 5, /**/ 7, 8, 9],
+            _ => unreachable!(),
+        };
+
+        cx.render(rsx!(order
+            .iter()
+            .map(|i| rsx!(Child { key: "{i}", show: *i != 4 && *i != 6 }))))
+    });
+
+    _ = dom.rebuild();
+
+    // The placeholder of the 6th child is moved in front of the placeholder of the 4th
+    dom.mark_dirty(ScopeId(0));
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [
+            PushRoot { id: ElementId(7,) },
+            InsertBefore { id: ElementId(5,), m: 1 },
+        ]
+    );
+}
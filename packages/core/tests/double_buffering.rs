@@ -0,0 +1,64 @@
+//! Every scope renders into one of two bump frames, and the frame from two renders ago is reset and reused
+
+use dioxus::prelude::*;
+use std::cell::RefCell;
+
+thread_local! {
+    static ALLOCATED: RefCell<Vec<usize>> = RefCell::new(Vec::new());
+}
+
+fn app(cx: Scope) -> Element {
+    // Allocate enough that the frame has to grow past its starting capacity
+    cx.bump().alloc_slice_fill_copy(4096, 0u8);
+
+    let allocated = cx.bump().allocated_bytes();
+    ALLOCATED.with(|log| log.borrow_mut().push(allocated));
+
+    cx.render(rsx! { div { "{cx.generation()}" } })
+}
+
+#[test]
+fn frames_are_reused_between_renders() {
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    for _ in 0..6 {
+        dom.mark_dirty(ScopeId(0));
+        _ = dom.render_immediate();
+    }
+
+    let allocated = ALLOCATED.with(|log| log.take());
+    assert_eq!(allocated.len(), 7);
+
+    // Once each frame has grown to fit a render, resetting it keeps that capacity around for the next render into it
+    assert_eq!(allocated[2], allocated[4]);
+    assert_eq!(allocated[4], allocated[6]);
+    assert_eq!(allocated[3], allocated[5]);
+
+    // And the frames never grow past what the first renders needed
+    assert!(allocated[6] <= allocated[0].max(allocated[1]));
+}
+
+#[cfg(feature = "frame-stats")]
+#[test]
+fn frame_stats_count_reused_memory() {
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // Give both frames a chance to grow to fit a render
+    for _ in 0..3 {
+        dom.mark_dirty(ScopeId(0));
+        _ = dom.render_immediate();
+    }
+    let warmed_up = dom.frame_stats();
+
+    for _ in 0..4 {
+        dom.mark_dirty(ScopeId(0));
+        _ = dom.render_immediate();
+    }
+    let stats = dom.frame_stats();
+
+    // Every render after that fits in memory the frames already had
+    assert_eq!(stats.allocated, warmed_up.allocated);
+    assert!(stats.reused > warmed_up.reused);
+}
@@ -1,6 +1,11 @@
 #![allow(non_snake_case)]
 
+use dioxus::core::Mutation::*;
 use dioxus::prelude::*;
+use std::{
+    rc::Rc,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 #[test]
 fn catches_panic() {
@@ -32,3 +37,115 @@ fn ThrowChild(cx: Scope) -> Element {
         div {}
     })
 }
+
+#[test]
+fn panicking_child_is_replaced_with_placeholder() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div { PanicChild {} }
+        })
+    }
+
+    fn PanicChild(cx: Scope) -> Element {
+        if cx.generation() > 0 {
+            panic!("boom");
+        }
+
+        cx.render(rsx! { "not yet" })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.mark_dirty(ScopeId(1));
+    assert!(dom.try_progress().is_ok());
+}
+
+#[test]
+fn panicking_root_is_returned() {
+    fn app(cx: Scope) -> Element {
+        if cx.generation() > 0 {
+            panic!("boom");
+        }
+
+        cx.render(rsx! { "hello" })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.mark_dirty(ScopeId(0));
+    let panicked = dom.try_progress().err().unwrap();
+    assert_eq!(panicked.scope, ScopeId(0));
+    assert_eq!(panicked.message, "boom");
+}
+
+#[test]
+fn boundaries_render_a_fallback() {
+    fn app(cx: Scope) -> Element {
+        let boundary = cx.use_error_boundary();
+
+        if let Some(error) = boundary.error() {
+            return cx.render(rsx!("{error.scope:?} failed"));
+        }
+
+        cx.render(rsx!(ThrowChild {}))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // The boundary re-renders with the error the child threw while it was being built
+    let edits = dom.render_immediate().santize().edits;
+    assert!(edits
+        .iter()
+        .any(|edit| matches!(edit, CreateTextNode { value: "ScopeId(1) failed", .. })));
+}
+
+#[test]
+fn clearing_a_boundary_renders_the_children_again() {
+    static FAIL: AtomicBool = AtomicBool::new(true);
+
+    fn app(cx: Scope) -> Element {
+        let boundary = cx.use_error_boundary();
+
+        if let Some(error) = boundary.error() {
+            assert_eq!(error.scope, ScopeId(1));
+            return cx.render(rsx! {
+                button { onclick: move |_| boundary.clear(), "retry" }
+            });
+        }
+
+        cx.render(rsx!(Flaky {}))
+    }
+
+    fn Flaky(cx: Scope) -> Element {
+        if FAIL.load(Ordering::SeqCst) {
+            panic!("flaky");
+        }
+
+        let status = "recovered";
+        cx.render(rsx!("{status}"))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // The panic was caught by the boundary instead of reaching the root
+    let edits = dom.try_progress().unwrap().santize().edits;
+    let button = edits
+        .iter()
+        .find_map(|edit| match edit {
+            NewEventListener { name: "click", id } => Some(*id),
+            _ => None,
+        })
+        .unwrap();
+
+    FAIL.store(false, Ordering::SeqCst);
+    dom.handle_event("click", Rc::new(MouseData::default()), button, true);
+
+    let edits = dom.render_immediate().santize().edits;
+    assert!(edits
+        .iter()
+        .any(|edit| matches!(edit, CreateTextNode { value: "recovered", .. })));
+}
@@ -0,0 +1,184 @@
+#![allow(non_snake_case)]
+
+//! Events should bubble from the target up through every ancestor, even across templates and components
+
+use dioxus::core::ElementId;
+use dioxus::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+thread_local! {
+    static LOG: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+fn log(name: &'static str) {
+    LOG.with(|log| log.borrow_mut().push(name));
+}
+
+fn take_log() -> Vec<&'static str> {
+    LOG.with(|log| log.take())
+}
+
+#[test]
+fn bubbles_through_nested_components() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div { onclick: move |_| log("outer"),
+                Middle {}
+            }
+        })
+    }
+
+    fn Middle(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div { onclick: move |_| log("middle"),
+                Inner {}
+            }
+        })
+    }
+
+    fn Inner(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div { onclick: move |_| log("inner") }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(3), true);
+    assert_eq!(take_log(), ["inner", "middle", "outer"]);
+
+    // Events that don't bubble are only delivered to the target
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(3), false);
+    assert_eq!(take_log(), ["inner"]);
+}
+
+#[test]
+fn bubbles_through_nested_elements() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div { onclick: move |_| log("outer"),
+                div { onclick: move |_| log("sibling") }
+                div { onclick: move |_| log("middle"),
+                    div { onclick: move |_| log("inner") }
+                }
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // The sibling is not an ancestor of the target, so it should never be called
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(4), true);
+    assert_eq!(take_log(), ["inner", "middle", "outer"]);
+}
+
+#[test]
+fn stop_propagation_halts_bubbling() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div { onclick: move |_| log("outer"),
+                Middle {}
+            }
+        })
+    }
+
+    fn Middle(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div {
+                onclick: move |evt| {
+                    log("middle");
+                    evt.stop_propagation();
+                },
+                div { onclick: move |_| log("inner") }
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(3), true);
+    assert_eq!(take_log(), ["inner", "middle"]);
+}
+
+#[test]
+fn listeners_survive_rerenders() {
+    fn app(cx: Scope) -> Element {
+        let count = cx.generation();
+        cx.render(rsx! {
+            div { onclick: move |_| log("outer"),
+                div { onclick: move |_| log("inner"), "{count}" }
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // Every render swaps the frame the templates live in, so the listeners need to follow the new templates
+    for _ in 0..3 {
+        dom.mark_dirty(ScopeId(0));
+        _ = dom.render_immediate();
+
+        dom.handle_event("click", Rc::new(MouseData::default()), ElementId(2), true);
+        assert_eq!(take_log(), ["inner", "outer"]);
+    }
+}
+
+#[test]
+fn capture_listeners_run_outside_in_before_bubbling() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div {
+                onclickcapture: move |_| log("outer capture"),
+                onclick: move |_| log("outer"),
+                Inner {}
+            }
+        })
+    }
+
+    fn Inner(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div {
+                onclickcapture: move |_| log("inner capture"),
+                onclick: move |_| log("inner")
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(2), true);
+    assert_eq!(
+        take_log(),
+        ["outer capture", "inner capture", "inner", "outer"]
+    );
+
+    // The capture phase runs through every ancestor even if the event doesn't bubble
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(2), false);
+    assert_eq!(take_log(), ["outer capture", "inner capture", "inner"]);
+}
+
+#[test]
+fn stop_propagation_in_capture_skips_the_target() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div {
+                onclickcapture: move |evt| {
+                    log("outer capture");
+                    evt.stop_propagation();
+                },
+                div { onclick: move |_| log("inner") }
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(2), true);
+    assert_eq!(take_log(), ["outer capture"]);
+}
@@ -0,0 +1,39 @@
+#![allow(non_snake_case)]
+
+//! Event handlers passed through props should be callable, cheaply cloneable, and default to a no-op
+
+use dioxus::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[test]
+fn handlers_are_called_through_clones() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            Child { onevent: move |amt: usize| { CALLS.fetch_add(amt, Ordering::Relaxed); } }
+            Child {}
+        })
+    }
+
+    #[derive(Props)]
+    struct ChildProps<'a> {
+        #[props(default)]
+        onevent: EventHandler<'a, usize>,
+    }
+
+    fn Child<'a>(cx: Scope<'a, ChildProps<'a>>) -> Element {
+        let cloned = cx.props.onevent.clone();
+
+        cx.props.onevent.call(1);
+        cloned.call(2);
+
+        cx.render(rsx!(div {}))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // the default handler of the second child is a no-op
+    assert_eq!(CALLS.load(Ordering::Relaxed), 3);
+}
@@ -0,0 +1,54 @@
+#![allow(non_snake_case)]
+
+//! Hooks keep their state between renders as long as they're called in the same order
+
+use dioxus::core::{ElementId, Mutation::*};
+use dioxus::prelude::*;
+
+#[test]
+fn hooks_keep_their_state_between_renders() {
+    fn app(cx: Scope) -> Element {
+        let count = cx.use_hook(|| 0);
+        *count += 1;
+        let name = cx.use_hook(|| String::from("jane"));
+
+        cx.render(rsx!("{name} rendered {count} times"))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    assert_eq!(
+        dom.rebuild().santize().edits,
+        [
+            CreateTextNode { value: "jane rendered 1 times", id: ElementId(1) },
+            AppendChildren { m: 1, id: ElementId(0) },
+        ]
+    );
+
+    dom.mark_dirty(ScopeId(0));
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetText { value: "jane rendered 2 times", id: ElementId(1) }]
+    );
+}
+
+#[test]
+fn conditional_hooks_panic() {
+    fn app(cx: Scope) -> Element {
+        if cx.generation() == 0 {
+            cx.use_hook(|| 0);
+        }
+        cx.use_hook(|| String::from("jane"));
+
+        cx.render(rsx!("hello"))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // The second render skips the first hook, so the string hook finds the number in its slot
+    dom.mark_dirty(ScopeId(0));
+    let panicked = dom.try_progress().err().unwrap();
+    assert!(panicked
+        .message
+        .contains("Unable to retrieve the hook that was initialized at this index"));
+}
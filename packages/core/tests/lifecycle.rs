@@ -4,8 +4,8 @@
 //! Tests for the lifecycle of components.
 use dioxus::core::{ElementId, Mutation::*};
 use dioxus::prelude::*;
-use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use std::{cell::RefCell, rc::Rc};
 
 type Shared<T> = Arc<Mutex<T>>;
 
@@ -70,6 +70,146 @@ fn events_generate() {
     )
 }
 
+#[test]
+fn removed_components_drop_hooks_child_first() {
+    struct OnDrop(&'static str, Shared<Vec<&'static str>>);
+
+    impl Drop for OnDrop {
+        fn drop(&mut self) {
+            self.1.lock().unwrap().push(self.0);
+        }
+    }
+
+    #[derive(Props)]
+    struct LogProps {
+        log: Shared<Vec<&'static str>>,
+    }
+
+    impl PartialEq for LogProps {
+        fn eq(&self, other: &Self) -> bool {
+            Arc::ptr_eq(&self.log, &other.log)
+        }
+    }
+
+    fn app(cx: Scope<LogProps>) -> Element {
+        match cx.generation() {
+            0 => cx.render(rsx!(Parent { log: cx.props.log.clone() })),
+            _ => cx.render(rsx!(())),
+        }
+    }
+
+    fn Parent(cx: Scope<LogProps>) -> Element {
+        cx.use_hook(|| OnDrop("parent", cx.props.log.clone()));
+        cx.render(rsx!(div { Child { log: cx.props.log.clone() } }))
+    }
+
+    fn Child(cx: Scope<LogProps>) -> Element {
+        cx.use_hook(|| OnDrop("child", cx.props.log.clone()));
+        cx.render(rsx!("child"))
+    }
+
+    let log = Shared::default();
+    let mut dom = VirtualDom::new_with_props(app, LogProps { log: log.clone() });
+    _ = dom.rebuild();
+    assert!(log.lock().unwrap().is_empty());
+
+    // Removing the parent tears down the whole subtree, running the child's cleanup before the parent's
+    dom.mark_dirty(ScopeId(0));
+    _ = dom.render_immediate();
+    assert_eq!(*log.lock().unwrap(), ["child", "parent"]);
+}
+
+#[test]
+fn removed_subtrees_free_their_scopes() {
+    fn app(cx: Scope) -> Element {
+        match cx.generation() % 2 {
+            0 => cx.render(rsx!(Top {})),
+            _ => cx.render(rsx!(())),
+        }
+    }
+
+    fn Top(cx: Scope) -> Element {
+        cx.render(rsx!(div { Middle {} }))
+    }
+
+    fn Middle(cx: Scope) -> Element {
+        cx.render(rsx!(div { Bottom {} }))
+    }
+
+    fn Bottom(cx: Scope) -> Element {
+        cx.render(rsx!("bottom"))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+    assert!((1..=3).all(|id| dom.get_scope(ScopeId(id)).is_some()));
+
+    // Removing the top component removes every scope below it too
+    dom.mark_dirty(ScopeId(0));
+    _ = dom.render_immediate();
+    assert!((1..=3).all(|id| dom.get_scope(ScopeId(id)).is_none()));
+
+    // The freed slots are reused when the subtree is mounted again
+    dom.mark_dirty(ScopeId(0));
+    _ = dom.render_immediate();
+    assert!((1..=3).all(|id| dom.get_scope(ScopeId(id)).is_some()));
+    assert!(dom.get_scope(ScopeId(4)).is_none());
+}
+
+#[test]
+fn on_lifecycle_sees_the_element_and_props() {
+    thread_local! {
+        static CALLS: RefCell<Vec<String>> = RefCell::new(Vec::new());
+    }
+
+    fn log(call: String) {
+        CALLS.with(|calls| calls.borrow_mut().push(call));
+    }
+
+    fn app(cx: Scope) -> Element {
+        match cx.generation() {
+            0 | 1 => cx.render(rsx!(Widget { value: cx.generation() })),
+            _ => cx.render(rsx!(())),
+        }
+    }
+
+    #[derive(Props, PartialEq)]
+    struct WidgetProps {
+        value: usize,
+    }
+
+    fn Widget(cx: Scope<WidgetProps>) -> Element {
+        let onmounted = use_on_lifecycle(
+            cx,
+            |evt: MountedEvent| log(format!("mount {:?}", evt.data.id)),
+            |props| log(format!("update {}", props.value)),
+            || log("cleanup".to_string()),
+        );
+        cx.render(rsx!(div { onmounted: onmounted }))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+    CALLS.with(|calls| assert!(calls.borrow().is_empty()));
+
+    // The renderer fires the mounted event once it has added the element to the page
+    let mounted = Rc::new(MountedData::new(ElementId(1)));
+    dom.handle_event("mounted", mounted, ElementId(1), false);
+
+    dom.mark_dirty(ScopeId(0));
+    _ = dom.render_immediate();
+
+    dom.mark_dirty(ScopeId(0));
+    _ = dom.render_immediate();
+
+    CALLS.with(|calls| {
+        assert_eq!(
+            *calls.borrow(),
+            ["mount ElementId(1)", "update 1", "cleanup"]
+        )
+    });
+}
+
 // #[test]
 // fn components_generate() {
 //     fn app(cx: Scope) -> Element {
@@ -0,0 +1,83 @@
+#![allow(non_snake_case)]
+
+//! Components whose props didn't change are skipped when their parent re-renders
+
+use dioxus::prelude::*;
+use std::cell::RefCell;
+
+thread_local! {
+    static RENDERS: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+fn rendered(name: &'static str) {
+    RENDERS.with(|renders| renders.borrow_mut().push(name));
+}
+
+fn take_renders() -> Vec<&'static str> {
+    RENDERS.with(|renders| renders.take())
+}
+
+#[derive(Props, PartialEq)]
+struct ValueProps {
+    value: usize,
+}
+
+fn Unchanged(cx: Scope<ValueProps>) -> Element {
+    rendered("unchanged");
+    cx.render(rsx!("{cx.props.value}"))
+}
+
+fn Changed(cx: Scope<ValueProps>) -> Element {
+    rendered("changed");
+    cx.render(rsx!("{cx.props.value}"))
+}
+
+#[derive(Props)]
+struct BorrowedProps<'a> {
+    value: &'a str,
+}
+
+fn Borrowed<'a>(cx: Scope<'a, BorrowedProps<'a>>) -> Element {
+    rendered("borrowed");
+    cx.render(rsx!("{cx.props.value}"))
+}
+
+#[test]
+fn equal_props_skip_the_render() {
+    fn app(cx: Scope) -> Element {
+        rendered("app");
+        let generation = cx.generation();
+        cx.render(rsx! {
+            Unchanged { value: 0 }
+            Changed { value: generation }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+    assert_eq!(take_renders(), ["app", "unchanged", "changed"]);
+
+    for _ in 0..3 {
+        dom.mark_dirty(ScopeId(0));
+        _ = dom.render_immediate();
+        assert_eq!(take_renders(), ["app", "changed"]);
+    }
+}
+
+#[test]
+fn borrowed_props_always_render() {
+    fn app(cx: Scope) -> Element {
+        rendered("app");
+        let name = cx.use_hook(|| String::from("jane"));
+        cx.render(rsx!(Borrowed { value: name }))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+    assert_eq!(take_renders(), ["app", "borrowed"]);
+
+    // Props that borrow from the parent can't be compared with the ones from its last render
+    dom.mark_dirty(ScopeId(0));
+    _ = dom.render_immediate();
+    assert_eq!(take_renders(), ["app", "borrowed"]);
+}
@@ -0,0 +1,95 @@
+//! Scopes marked as offscreen should skip re-renders until they become visible again
+
+use dioxus::core::{ElementId, Mutation::*};
+use dioxus::prelude::*;
+
+#[test]
+fn offscreen_scopes_defer_prop_changes() {
+    fn app(cx: Scope) -> Element {
+        let value = cx.generation();
+        cx.render(rsx!(child { value: value }))
+    }
+
+    #[inline_props]
+    fn child(cx: Scope, value: usize) -> Element {
+        cx.render(rsx!("{value}"))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    assert_eq!(
+        dom.rebuild().santize().edits,
+        [
+            CreateTextNode { value: "0", id: ElementId(1) },
+            AppendChildren { m: 1, id: ElementId(0) },
+        ]
+    );
+
+    dom.get_scope(ScopeId(1)).unwrap().set_offscreen(true);
+
+    // The parent re-renders, but the child keeps its last output
+    dom.mark_dirty(ScopeId(0));
+    assert!(dom.render_immediate().santize().edits.is_empty());
+
+    dom.mark_dirty(ScopeId(0));
+    assert!(dom.render_immediate().santize().edits.is_empty());
+
+    // Once visible, the child renders with the latest props it was given
+    dom.get_scope(ScopeId(1)).unwrap().set_offscreen(false);
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetText { value: "2", id: ElementId(1) }]
+    );
+}
+
+#[test]
+fn offscreen_scopes_defer_their_own_updates() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx!("{cx.generation()}"))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.base_scope().set_offscreen(true);
+    dom.mark_dirty(ScopeId(0));
+    assert!(dom.render_immediate().santize().edits.is_empty());
+
+    dom.base_scope().set_offscreen(false);
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetText { value: "1", id: ElementId(1) }]
+    );
+}
+
+#[test]
+fn newer_props_replace_the_deferred_ones() {
+    fn app(cx: Scope) -> Element {
+        let value = cx.generation();
+        cx.render(rsx!(child { value: value }))
+    }
+
+    #[inline_props]
+    fn child(cx: Scope, value: usize) -> Element {
+        cx.render(rsx!("{value}"))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // The child holds on to the props of the parent's next render while it's offscreen
+    dom.get_scope(ScopeId(1)).unwrap().set_offscreen(true);
+    dom.mark_dirty(ScopeId(0));
+    assert!(dom.render_immediate().santize().edits.is_empty());
+
+    // Once visible, the parent renders again before the child picks up the deferred props
+    dom.get_scope(ScopeId(1)).unwrap().set_offscreen(false);
+    dom.mark_dirty(ScopeId(0));
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetText { value: "2", id: ElementId(1) }]
+    );
+
+    // The deferred props are older than the ones the child rendered with, so they're never used
+    dom.mark_dirty(ScopeId(1));
+    assert!(dom.render_immediate().santize().edits.is_empty());
+}
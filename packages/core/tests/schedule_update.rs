@@ -0,0 +1,38 @@
+#![allow(non_snake_case)]
+
+//! A component deep in the tree should be able to ask an ancestor to re-render without drilling props through
+
+use dioxus::core::{ElementId, Mutation::*};
+use dioxus::prelude::*;
+use std::{rc::Rc, sync::Arc};
+
+#[derive(Clone)]
+struct Refresh(Arc<dyn Fn() + Send + Sync>);
+
+fn app(cx: Scope) -> Element {
+    cx.use_hook(|| cx.provide_context(Refresh(cx.schedule_update())));
+    cx.render(rsx!( "{cx.generation()}" Middle {} ))
+}
+
+fn Middle(cx: Scope) -> Element {
+    cx.render(rsx!(Leaf {}))
+}
+
+fn Leaf(cx: Scope) -> Element {
+    let refresh = cx.consume_context::<Refresh>().unwrap();
+    cx.render(rsx!(div { onclick: move |_| (refresh.0)() }))
+}
+
+#[test]
+fn descendants_can_rerender_an_ancestor() {
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(2), true);
+
+    // Only the root re-renders, the memoized children it renders are left untouched
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetText { value: "1", id: ElementId(1) }]
+    );
+}
@@ -0,0 +1,71 @@
+#![allow(non_snake_case)]
+
+//! Scopes should know where they are in the tree, so a router at the root can update the outlets below it
+
+use dioxus::core::{ElementId, Mutation::*};
+use dioxus::prelude::*;
+use std::{cell::Cell, rc::Rc};
+
+#[derive(Clone, Default)]
+struct Router {
+    route: Rc<Cell<&'static str>>,
+    outlet: Rc<Cell<Option<ScopeId>>>,
+}
+
+fn app(cx: Scope) -> Element {
+    cx.use_hook(|| cx.provide_context(Router::default()));
+    cx.render(rsx!(Layout {}))
+}
+
+fn Layout(cx: Scope) -> Element {
+    cx.render(rsx!(Outlet {}))
+}
+
+fn Outlet(cx: Scope) -> Element {
+    let router = cx.consume_context::<Router>().unwrap();
+
+    assert_eq!(cx.ancestors().collect::<Vec<_>>(), [ScopeId(1), ScopeId(0)]);
+    assert_eq!(cx.ancestor_names().collect::<Vec<_>>(), ["Layout", "app"]);
+    assert_eq!(cx.root_scope(), ScopeId(0));
+
+    router.outlet.set(Some(cx.scope_id()));
+
+    cx.render(rsx!("{router.route.get()}"))
+}
+
+#[test]
+fn router_updates_nested_outlet() {
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    let root = dom.base_scope();
+    assert_eq!(root.ancestors().count(), 0);
+    assert_eq!(root.root_scope(), ScopeId(0));
+
+    let router = root.consume_context::<Router>().unwrap();
+    router.route.set("/about");
+
+    // Only the outlet re-renders, the layout in between is left alone
+    root.needs_update_any(router.outlet.get().unwrap());
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetText { value: "/about", id: ElementId(1) }]
+    );
+}
+
+#[test]
+fn subtree_updates_reach_memoized_children() {
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    let root = dom.base_scope();
+    let router = root.consume_context::<Router>().unwrap();
+    router.route.set("/blog");
+
+    // The layout has no props, so a normal update of the root wouldn't reach the outlet
+    root.needs_update_subtree();
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetText { value: "/blog", id: ElementId(1) }]
+    );
+}
@@ -98,3 +98,113 @@ async fn async_text(cx: Scope<'_>) -> Element {
 
     cx.render(rsx!( div { "Hello! {username}, you are {age}, {_user} {_age}" } ))
 }
+
+#[test]
+fn progress_completely_drains_nested_suspense() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let mut dom = VirtualDom::new(nested_app);
+            _ = dom.rebuild();
+            assert!(dom.has_suspended_work());
+
+            // The outer child mounts another suspended child once it resolves, and both are waited on
+            let edits = dom.progress_completely().await.santize().edits;
+            assert!(!edits.is_empty());
+            assert!(!dom.has_suspended_work());
+        });
+}
+
+fn nested_app(cx: Scope) -> Element {
+    cx.use_hook(|| {
+        cx.provide_context(Rc::new(SuspenseContext::new(cx.scope_id())));
+    });
+
+    cx.render(rsx!(outer_child {}))
+}
+
+async fn outer_child(cx: Scope<'_>) -> Element {
+    use_future!(cx, || tokio::time::sleep(Duration::from_millis(5))).await;
+    cx.render(rsx!(inner_child {}))
+}
+
+async fn inner_child(cx: Scope<'_>) -> Element {
+    use_future!(cx, || tokio::time::sleep(Duration::from_millis(5))).await;
+    cx.render(rsx!( div { "done" } ))
+}
+
+#[test]
+fn fallback_is_shown_until_the_tree_resolves() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let mut dom = VirtualDom::new(fallback_app);
+
+            // The fallback component is mounted in place of the suspended tree
+            let mutations = dom.rebuild();
+            let spinner = mutations
+                .templates
+                .iter()
+                .find(|template| {
+                    matches!(
+                        template.roots,
+                        [TemplateNode::Element {
+                            tag: "div",
+                            attrs: [TemplateAttribute::Static { value: "spinner", .. }],
+                            ..
+                        }]
+                    )
+                })
+                .unwrap()
+                .name;
+            let fallback = mutations
+                .edits
+                .iter()
+                .find_map(|edit| match edit {
+                    LoadTemplate { name, id, .. } if *name == spinner => Some(*id),
+                    _ => None,
+                })
+                .unwrap();
+
+            // Once the child resolves, the whole boundary is inserted where the fallback was and the fallback is removed
+            let edits = dom.progress_completely().await.santize().edits;
+            let inserted = edits
+                .iter()
+                .position(|edit| matches!(edit, InsertBefore { id, .. } if *id == fallback))
+                .unwrap();
+            let removed = edits
+                .iter()
+                .position(|edit| matches!(edit, Remove { id } if *id == fallback))
+                .unwrap();
+            assert!(inserted < removed);
+            assert!(!dom.has_suspended_work());
+        });
+}
+
+fn fallback_app(cx: Scope) -> Element {
+    cx.render(rsx!(div { loading_boundary {} }))
+}
+
+fn loading_boundary(cx: Scope) -> Element {
+    cx.use_hook(|| {
+        cx.provide_context(Rc::new(SuspenseContext::with_fallback(
+            cx.scope_id(),
+            spinner,
+        )));
+    });
+
+    cx.render(rsx!(slow_child {}))
+}
+
+fn spinner(cx: Scope) -> Element {
+    cx.render(rsx!( div { class: "spinner", "Loading..." } ))
+}
+
+async fn slow_child(cx: Scope<'_>) -> Element {
+    use_future!(cx, || tokio::time::sleep(Duration::from_millis(5))).await;
+    cx.render(rsx!( div { "loaded" } ))
+}
@@ -1,5 +1,6 @@
 //! Verify that tasks get polled by the virtualdom properly, and that we escape wait_for_work safely
 
+use dioxus::core::{ElementId, Mutation::SetText};
 use dioxus::prelude::*;
 use std::time::Duration;
 
@@ -40,3 +41,31 @@ fn app(cx: Scope) -> Element {
 
     cx.render(rsx!(()))
 }
+
+#[tokio::test]
+async fn wait_for_work_wakes_on_updates_from_tasks() {
+    fn app(cx: Scope) -> Element {
+        cx.use_hook(|| {
+            let update = cx.schedule_update();
+            cx.spawn(async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                update();
+            });
+        });
+
+        cx.render(rsx!("{cx.generation()}"))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(Duration::from_millis(500)) => panic!("wait_for_work never resolved"),
+    };
+
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetText { value: "1", id: ElementId(1) }]
+    );
+}
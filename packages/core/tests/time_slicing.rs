@@ -0,0 +1,55 @@
+#![allow(non_snake_case)]
+
+//! Big updates can be rendered a few scopes at a time, so the renderer can yield to the event loop between slices
+
+use dioxus::core::{ElementId, Mutation::*};
+use dioxus::prelude::*;
+
+fn app(cx: Scope) -> Element {
+    cx.render(rsx! {
+        Child {}
+        Child {}
+    })
+}
+
+fn Child(cx: Scope) -> Element {
+    let generation = cx.generation();
+    cx.render(rsx!("{generation}"))
+}
+
+#[test]
+fn render_until_stops_once_expired() {
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.mark_dirty(ScopeId(1));
+    dom.mark_dirty(ScopeId(2));
+
+    // Every slice renders at least one scope, so an expired deadline still makes progress
+    assert_eq!(
+        dom.render_until(|| true).santize().edits,
+        [SetText { value: "1", id: ElementId(1) }]
+    );
+    assert_eq!(
+        dom.render_until(|| true).santize().edits,
+        [SetText { value: "1", id: ElementId(2) }]
+    );
+    assert!(dom.render_until(|| true).edits.is_empty());
+}
+
+#[test]
+fn render_until_renders_everything_with_time_left() {
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.mark_dirty(ScopeId(1));
+    dom.mark_dirty(ScopeId(2));
+
+    assert_eq!(
+        dom.render_until(|| false).santize().edits,
+        [
+            SetText { value: "1", id: ElementId(1) },
+            SetText { value: "1", id: ElementId(2) },
+        ]
+    );
+}
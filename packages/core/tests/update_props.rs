@@ -0,0 +1,45 @@
+#![allow(non_snake_case)]
+
+//! The root props can be replaced from outside of the VirtualDom, re-rendering the root with them
+
+use dioxus::core::{ElementId, Mutation::*};
+use dioxus::prelude::*;
+
+#[derive(PartialEq)]
+struct AppProps {
+    name: &'static str,
+}
+
+fn app(cx: Scope<AppProps>) -> Element {
+    cx.render(rsx!("hello {cx.props.name}"))
+}
+
+#[test]
+fn new_props_rerender_the_root() {
+    let mut dom = VirtualDom::new_with_props(app, AppProps { name: "jane" });
+    _ = dom.rebuild();
+
+    dom.update_props(AppProps { name: "jack" });
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetText { value: "hello jack", id: ElementId(1) }]
+    );
+
+    // Equal props don't cause a render
+    dom.update_props(AppProps { name: "jack" });
+    assert!(dom.render_immediate().santize().edits.is_empty());
+}
+
+#[test]
+fn props_updated_before_rebuild_are_rendered() {
+    let mut dom = VirtualDom::new_with_props(app, AppProps { name: "jane" });
+    dom.update_props(AppProps { name: "jack" });
+
+    assert_eq!(
+        dom.rebuild().santize().edits,
+        [
+            CreateTextNode { value: "hello jack", id: ElementId(1) },
+            AppendChildren { m: 1, id: ElementId(0) },
+        ]
+    );
+}
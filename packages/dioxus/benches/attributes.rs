@@ -0,0 +1,94 @@
+#![allow(non_snake_case)]
+//! This benchmark re-renders attributes that didn't change.
+//!
+//! Borrowed attribute text, like a `&'static str`, points to the same memory on every render. Diffing it only compares
+//! the pointers, and nothing is copied into the bump arena. Formatted text is copied into the arena on every render and
+//! compared byte by byte. Criterion reports the bump memory each render uses as its throughput, and the benchmark
+//! checks that borrowing the text saves at least the bytes the formatted text copies.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use dioxus::prelude::*;
+
+criterion_group!(mbenches, rerender_attributes);
+criterion_main!(mbenches);
+
+const ROWS: usize = 1_000;
+
+static CLASSES: &[&str] = &[
+    "col-md-1 text-center align-middle border-bottom",
+    "col-md-4 text-start align-middle border-bottom fw-bold",
+    "col-md-6 text-end align-middle border-bottom text-muted",
+    "col-md-1 text-center align-middle border-bottom glyphicon",
+];
+
+fn Borrowed(cx: Scope) -> Element {
+    render!(
+        div {
+            (0..ROWS).map(|i| {
+                let class = CLASSES[i % CLASSES.len()];
+                rsx!( div { class: class } )
+            })
+        }
+    )
+}
+
+fn Formatted(cx: Scope) -> Element {
+    render!(
+        div {
+            (0..ROWS).map(|i| {
+                let class = CLASSES[i % CLASSES.len()];
+                rsx!( div { class: "{class}" } )
+            })
+        }
+    )
+}
+
+fn rerender_attributes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rerender attributes");
+    let mut bytes = Vec::new();
+
+    for (name, app) in [
+        ("borrowed", Borrowed as Component),
+        ("formatted", Formatted),
+    ] {
+        let mut dom = VirtualDom::new(app);
+        _ = dom.rebuild();
+
+        // Let both frames grow to fit a render before measuring
+        for _ in 0..3 {
+            dom.mark_dirty(ScopeId(0));
+            _ = dom.render_immediate();
+        }
+
+        let used = bump_bytes_per_render(&mut dom);
+        bytes.push(used);
+
+        group.throughput(Throughput::Bytes(used as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                dom.mark_dirty(ScopeId(0));
+                let edits = dom.render_immediate();
+                assert!(edits.edits.is_empty());
+            })
+        });
+    }
+
+    group.finish();
+
+    // Formatting copies every class into the arena, which borrowing it skips
+    let copied: usize = (0..ROWS).map(|i| CLASSES[i % CLASSES.len()].len()).sum();
+    let (borrowed, formatted) = (bytes[0], bytes[1]);
+    assert!(
+        formatted >= borrowed + copied,
+        "borrowed attributes used {borrowed} bump bytes per render, formatted ones used {formatted}"
+    );
+}
+
+fn bump_bytes_per_render(dom: &mut VirtualDom) -> usize {
+    let before = dom.frame_stats();
+    dom.mark_dirty(ScopeId(0));
+    _ = dom.render_immediate();
+    let after = dom.frame_stats();
+
+    (after.allocated + after.reused) - (before.allocated + before.reused)
+}
@@ -47,3 +47,11 @@ pub use usecallback::*;
 
 mod usememo;
 pub use usememo::*;
+
+mod uselifecycle;
+pub use uselifecycle::*;
+
+#[cfg(feature = "serialize")]
+mod usesnapshot;
+#[cfg(feature = "serialize")]
+pub use usesnapshot::*;
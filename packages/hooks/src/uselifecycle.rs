@@ -0,0 +1,70 @@
+use dioxus_core::{Event, Scope};
+use std::cell::RefCell;
+
+/// Run imperative code at each point in a component's lifecycle.
+///
+/// This is an escape hatch for integrating widgets that aren't built with Dioxus, like maps or charting libraries,
+/// which need to be created, updated, and torn down by hand.
+///
+/// The hook returns a handler for the `onmounted` event of the element the widget is drawn into.
+///
+/// - `mount` is called with that element's mounted event once it has been added to the page
+/// - `update` is called with the props of every render after the widget was mounted
+/// - `cleanup` runs when the component is removed from the tree
+///
+/// `mount` is only called for the first time the element is mounted. `update` runs during the render, before its
+/// edits are applied, so the widget sees the new props before anything on the page changes. The `cleanup` closure
+/// from the latest render is the one that gets called.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn Chart(cx: Scope<ChartProps>) -> Element {
+///     let onmounted = use_on_lifecycle(
+///         cx,
+///         |evt: MountedEvent| chart::create(evt.data.id),
+///         |props| chart::set_data(&props.data),
+///         || chart::destroy(),
+///     );
+///
+///     cx.render(rsx!( div { onmounted: onmounted } ))
+/// }
+/// ```
+pub fn use_on_lifecycle<'a, P, T: 'static>(
+    cx: Scope<'a, P>,
+    mount: impl FnOnce(Event<T>) + 'static,
+    update: impl FnOnce(&'a P),
+    cleanup: impl FnOnce() + 'static,
+) -> impl FnMut(Event<T>) + 'a {
+    struct Lifecycle<T: 'static> {
+        // Taken once the element is mounted
+        mount: RefCell<Option<Box<dyn FnOnce(Event<T>)>>>,
+        cleanup: RefCell<Option<Box<dyn FnOnce()>>>,
+    }
+
+    impl<T> Drop for Lifecycle<T> {
+        fn drop(&mut self) {
+            if let Some(cleanup) = self.cleanup.get_mut().take() {
+                cleanup();
+            }
+        }
+    }
+
+    let state: &Lifecycle<T> = cx.use_hook(|| Lifecycle {
+        mount: RefCell::new(Some(Box::new(mount))),
+        cleanup: RefCell::new(None),
+    });
+
+    *state.cleanup.borrow_mut() = Some(Box::new(cleanup));
+
+    if state.mount.borrow().is_none() {
+        update(cx.props);
+    }
+
+    move |evt| {
+        let mount = state.mount.borrow_mut().take();
+        if let Some(mount) = mount {
+            mount(evt);
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use crate::{use_state, UseState};
+use dioxus_core::{ScopeId, ScopeState};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+};
+
+/// The state of every [`use_snapshot_state`] hook in a tree, captured with [`SnapshotRegistry::snapshot`].
+///
+/// Snapshots are plain data, so they can be serialized to save a session or step back through time while debugging.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    values: BTreeMap<String, serde_json::Value>,
+}
+
+impl Snapshot {
+    /// Check if the snapshot doesn't hold any state
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// Tracks every [`use_snapshot_state`] hook below the scope it is provided in.
+///
+/// Provide it as a context at the root of the tree, either empty to start fresh or with [`SnapshotRegistry::restoring`]
+/// to seed the hooks of a new tree from a snapshot.
+///
+/// ```rust, ignore
+/// let registry = SnapshotRegistry::restoring(saved);
+/// let mut dom = VirtualDom::new(app);
+/// dom.base_scope().provide_context(registry.clone());
+/// _ = dom.rebuild();
+///
+/// // later
+/// let saved = registry.snapshot();
+/// ```
+#[derive(Clone, Default)]
+pub struct SnapshotRegistry {
+    inner: Rc<RefCell<Registry>>,
+}
+
+#[derive(Default)]
+struct Registry {
+    // The snapshot we're restoring from. Values are taken out as the hooks that match them are mounted
+    restoring: BTreeMap<String, serde_json::Value>,
+
+    // Where each scope on the path to a mounted hook sits in the tree
+    scopes: HashMap<ScopeId, ScopeSlot>,
+
+    // The hooks that are currently mounted
+    entries: BTreeMap<String, Box<dyn Fn() -> Option<serde_json::Value>>>,
+}
+
+struct ScopeSlot {
+    parent: Option<ScopeId>,
+    name: String,
+
+    // Tells apart siblings with the same name. Slots are reused once a scope is unmounted, so a component that is
+    // removed and mounted again gets its old position back
+    index: usize,
+
+    // How many mounted hooks are in this scope or below it. The slot is freed once there are none left
+    hooks_below: usize,
+
+    // How many hooks this scope has registered, in the order it called them
+    hooks: usize,
+}
+
+impl SnapshotRegistry {
+    /// Create a registry that seeds hooks from a snapshot as they are mounted
+    pub fn restoring(snapshot: Snapshot) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(Registry {
+                restoring: snapshot.values,
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Capture the current state of every mounted hook
+    pub fn snapshot(&self) -> Snapshot {
+        let inner = self.inner.borrow();
+        let values = inner
+            .entries
+            .iter()
+            .filter_map(|(key, read)| Some((key.clone(), read()?)))
+            .collect();
+
+        Snapshot { values }
+    }
+
+    /// Find the key of the next hook in a scope, like `app[0]/List[0]/Item[2]#1`
+    ///
+    /// Returns the key, the scopes the hook has to be released from when it's dropped, and the value to restore.
+    fn register(&self, cx: &ScopeState) -> (String, Vec<ScopeId>, Option<serde_json::Value>) {
+        let mut inner = self.inner.borrow_mut();
+
+        let mut path: Vec<(ScopeId, &str)> = std::iter::once((cx.scope_id(), cx.name()))
+            .chain(cx.ancestors().zip(cx.ancestor_names()))
+            .collect();
+        path.reverse();
+
+        let mut segments = Vec::with_capacity(path.len());
+        let mut parent = None;
+        for (id, name) in &path {
+            if !inner.scopes.contains_key(id) {
+                let index = (0..)
+                    .find(|index| {
+                        !inner.scopes.values().any(|slot| {
+                            slot.parent == parent && slot.name == *name && slot.index == *index
+                        })
+                    })
+                    .unwrap();
+                inner.scopes.insert(
+                    *id,
+                    ScopeSlot {
+                        parent,
+                        name: name.to_string(),
+                        index,
+                        hooks_below: 0,
+                        hooks: 0,
+                    },
+                );
+            }
+
+            let slot = inner.scopes.get_mut(id).unwrap();
+            slot.hooks_below += 1;
+            segments.push(format!("{}[{}]", name, slot.index));
+            parent = Some(*id);
+        }
+
+        let slot = inner.scopes.get_mut(&cx.scope_id()).unwrap();
+        let key = format!("{}#{}", segments.join("/"), slot.hooks);
+        slot.hooks += 1;
+
+        let restored = inner.restoring.remove(&key);
+        let scopes = path.into_iter().map(|(id, _)| id).collect();
+        (key, scopes, restored)
+    }
+
+    fn release(&self, key: &str, scopes: &[ScopeId]) {
+        let mut inner = self.inner.borrow_mut();
+        let removed = inner.entries.remove(key);
+
+        for id in scopes {
+            if let Some(slot) = inner.scopes.get_mut(id) {
+                slot.hooks_below -= 1;
+                if slot.hooks_below == 0 {
+                    inner.scopes.remove(id);
+                }
+            }
+        }
+
+        drop(inner);
+        drop(removed);
+    }
+}
+
+/// Store state like [`use_state`], but include it in the snapshots of the nearest [`SnapshotRegistry`].
+///
+/// Hooks are matched to the values in a snapshot by the path of components down to theirs and the order they are
+/// called in, so restoring into a tree of the same shape gives every hook back its value. Siblings with the same name
+/// are told apart by the order they were mounted in. Hooks that don't have a
+/// value in the snapshot, or whose value can't be deserialized, start from `initial_state_fn` instead.
+///
+/// Without a [`SnapshotRegistry`] above the component, this behaves exactly like [`use_state`].
+///
+/// ```rust, ignore
+/// fn Counter(cx: Scope) -> Element {
+///     let count = use_snapshot_state(cx, || 0);
+///
+///     cx.render(rsx! {
+///         button { onclick: move |_| count += 1, "{count}" }
+///     })
+/// }
+/// ```
+pub fn use_snapshot_state<T: Serialize + DeserializeOwned + 'static>(
+    cx: &ScopeState,
+    initial_state_fn: impl FnOnce() -> T,
+) -> &UseState<T> {
+    let entry = cx.use_hook(|| {
+        let registry = cx.consume_context::<SnapshotRegistry>()?;
+        let (key, scopes, restored) = registry.register(cx);
+        Some(SnapshotEntry {
+            registry,
+            key,
+            scopes,
+            restored,
+        })
+    });
+
+    let restored = entry
+        .as_mut()
+        .and_then(|entry| entry.restored.take())
+        .and_then(|value| serde_json::from_value(value).ok());
+
+    let state = use_state(cx, || restored.unwrap_or_else(initial_state_fn));
+
+    if let Some(entry) = entry {
+        let mut inner = entry.registry.inner.borrow_mut();
+        if !inner.entries.contains_key(&entry.key) {
+            let state = state.clone();
+            inner.entries.insert(
+                entry.key.clone(),
+                Box::new(move || serde_json::to_value(&*state.current()).ok()),
+            );
+        }
+    }
+
+    state
+}
+
+struct SnapshotEntry {
+    registry: SnapshotRegistry,
+    key: String,
+    scopes: Vec<ScopeId>,
+    restored: Option<serde_json::Value>,
+}
+
+impl Drop for SnapshotEntry {
+    fn drop(&mut self) {
+        // The hook is no longer mounted, so it shouldn't show up in snapshots anymore
+        self.registry.release(&self.key, &self.scopes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+
+    fn app(cx: Scope) -> Element {
+        let count = use_snapshot_state(cx, || 0);
+        let name = use_snapshot_state(cx, || String::from("initial"));
+
+        if cx.generation() == 0 {
+            count.set(**count + 1);
+            name.set(String::from("changed"));
+        }
+
+        VNode::empty()
+    }
+
+    #[test]
+    fn snapshots_restore_into_a_new_tree() {
+        let registry = SnapshotRegistry::default();
+        let mut dom = VirtualDom::new(app);
+        dom.base_scope().provide_context(registry.clone());
+        _ = dom.rebuild();
+
+        let snapshot = registry.snapshot();
+        assert!(!snapshot.is_empty());
+
+        // A fresh tree of the same shape picks up where the old one left off
+        let restored = SnapshotRegistry::restoring(snapshot.clone());
+        let mut dom = VirtualDom::new(app);
+        dom.base_scope().provide_context(restored.clone());
+        _ = dom.rebuild();
+
+        // The restored tree changed the values again on its first render
+        let values = restored.snapshot().values;
+        assert_eq!(values["app[0]#0"], serde_json::json!(2));
+        assert_eq!(values["app[0]#1"], serde_json::json!("changed"));
+    }
+
+    #[test]
+    fn mismatched_values_fall_back_to_the_initial_state() {
+        let mut values = BTreeMap::new();
+        values.insert(String::from("app[0]#0"), serde_json::json!("not a number"));
+
+        let registry = SnapshotRegistry::restoring(Snapshot { values });
+        let mut dom = VirtualDom::new(app);
+        dom.base_scope().provide_context(registry.clone());
+        _ = dom.rebuild();
+
+        assert_eq!(registry.snapshot().values["app[0]#0"], serde_json::json!(1));
+    }
+
+    #[test]
+    fn remounted_components_keep_their_keys() {
+        use dioxus_core::DynamicNode;
+        use std::cell::Cell;
+
+        fn parent(cx: Scope) -> Element {
+            // The counter is unmounted on the second render and mounted again on the third
+            let child = match cx.generation() {
+                1 => DynamicNode::Placeholder(Default::default()),
+                _ => cx.component(counter, (), "counter"),
+            };
+
+            Some(VNode {
+                key: None,
+                parent: None,
+                template: Cell::new(Template {
+                    name: "snapshot-remount",
+                    roots: &[TemplateNode::Dynamic { id: 0 }],
+                    node_paths: &[&[0]],
+                    attr_paths: &[],
+                }),
+                root_ids: Default::default(),
+                dynamic_nodes: cx.bump().alloc([child]),
+                dynamic_attrs: &[],
+            })
+        }
+
+        fn counter(cx: Scope) -> Element {
+            let count = use_snapshot_state(cx, || 0);
+            if cx.generation() == 0 {
+                count.set(**count + 1);
+            }
+            VNode::empty()
+        }
+
+        let registry = SnapshotRegistry::default();
+        let mut dom = VirtualDom::new(parent);
+        dom.base_scope().provide_context(registry.clone());
+        _ = dom.rebuild();
+
+        dom.mark_dirty(ScopeId(0));
+        _ = dom.render_immediate();
+        assert!(registry.snapshot().is_empty());
+
+        dom.mark_dirty(ScopeId(0));
+        _ = dom.render_immediate();
+
+        let values = registry.snapshot().values;
+        assert_eq!(values.keys().collect::<Vec<_>>(), ["app[0]/counter[0]#0"]);
+        assert_eq!(values["app[0]/counter[0]#0"], serde_json::json!(1));
+    }
+}
@@ -1596,5 +1596,10 @@ builder_constructors! {
     // /// element.
     // use "http://www.w3.org/2000/svg" {};
 
+    // MathML components
+    /// Build a
+    /// [`<math>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/math)
+    /// element.
+    math "http://www.w3.org/1998/Math/MathML" {};
 
 }
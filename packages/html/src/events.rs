@@ -25,39 +25,53 @@ macro_rules! impl_event {
 }
 
 mod animation;
+mod beforeinput;
 mod clipboard;
 mod composition;
+mod custom;
 mod drag;
 mod focus;
 mod form;
+mod history;
 mod image;
 mod keyboard;
 mod media;
+mod mounted;
 mod mouse;
 mod pointer;
+mod resize;
 mod scroll;
+mod security;
 mod selection;
 mod toggle;
 mod touch;
 mod transition;
+mod visible;
 mod wheel;
 
 pub use animation::*;
+pub use beforeinput::*;
 pub use clipboard::*;
 pub use composition::*;
+pub use custom::*;
 pub use drag::*;
 pub use focus::*;
 pub use form::*;
+pub use history::*;
 pub use image::*;
 pub use keyboard::*;
 pub use media::*;
+pub use mounted::*;
 pub use mouse::*;
 pub use pointer::*;
+pub use resize::*;
 pub use scroll::*;
+pub use security::*;
 pub use selection::*;
 pub use toggle::*;
 pub use touch::*;
 pub use transition::*;
+pub use visible::*;
 pub use wheel::*;
 
 pub fn event_bubbles(evt: &str) -> bool {
@@ -75,6 +89,7 @@ pub fn event_bubbles(evt: &str) -> bool {
         "focusout" => true,
         "focusin" => true,
         "blur" => false,
+        "beforeinput" => true,
         "change" => true,
         "input" => true,
         "invalid" => true,
@@ -110,6 +125,7 @@ pub fn event_bubbles(evt: &str) -> bool {
         "pointerleave" => false,
         "pointerover" => true,
         "pointerout" => true,
+        "pointerrawupdate" => true,
         "select" => true,
         "touchcancel" => true,
         "touchend" => true,
@@ -143,11 +159,33 @@ pub fn event_bubbles(evt: &str) -> bool {
         "animationend" => true,
         "animationiteration" => true,
         "transitionend" => true,
-        "toggle" => true,
+        "toggle" => false,
+        "beforetoggle" => false,
+        "securitypolicyviolation" => true,
+        "popstate" => false,
+        "hashchange" => false,
+        "mounted" => false,
+        "visible" => false,
+        "resize" => false,
         _ => true,
     }
 }
 
+/// The event a capture listener listens to, or `None` if the listener isn't a capture listener
+///
+/// Capture listeners, like `onclickcapture`, are called before the regular listeners of their event, starting at the
+/// outermost ancestor of the target and moving in. The VirtualDom takes care of that ordering, so renderers just need
+/// to listen for the event itself.
+pub fn capture_event(name: &str) -> Option<&str> {
+    // These events end in capture without being capture listeners
+    if matches!(name, "gotpointercapture" | "lostpointercapture") {
+        return None;
+    }
+
+    name.strip_suffix("capture")
+        .filter(|event| !event.is_empty())
+}
+
 use std::future::Future;
 
 #[doc(hidden)]
@@ -0,0 +1,25 @@
+use dioxus_core::Event;
+
+pub type BeforeInputEvent = Event<BeforeInputData>;
+
+/// An edit that is about to be made to an input, text area or contenteditable element.
+///
+/// Events are handled after the browser has dispatched them, so calling `prevent_default` in the handler can't cancel
+/// the edit. To block edits, add `prevent_default: "onbeforeinput"` to the element and apply the edits you want to
+/// keep yourself, for example by updating the value of a controlled input.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BeforeInputData {
+    /// The kind of edit, like `insertText`, `deleteContentBackward` or `insertFromPaste`
+    pub input_type: String,
+
+    /// The text that will be inserted, if any
+    pub data: Option<String>,
+}
+
+impl_event! {
+    BeforeInputData;
+
+    /// onbeforeinput
+    onbeforeinput
+}
@@ -1,10 +1,42 @@
+use std::{fmt::Debug, sync::Arc};
+
 use dioxus_core::Event;
 
+use crate::FileEngine;
+
 pub type ClipboardEvent = Event<ClipboardData>;
+
+/// The data on the clipboard when the user copies, cuts or pastes
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct ClipboardData {
-    // DOMDataTransfer clipboardData
+    /// The `text/plain` contents of the clipboard, if any
+    pub text: Option<String>,
+
+    /// The files or images that were pasted, if any
+    ///
+    /// Pasting a screenshot gives you a single image file, which you can read with [`FileEngine::read_file`].
+    #[cfg_attr(
+        feature = "serialize",
+        serde(default, with = "crate::events::form::serialize_files")
+    )]
+    pub files: Option<Arc<dyn FileEngine>>,
+}
+
+impl PartialEq for ClipboardData {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for ClipboardData {}
+
+impl Debug for ClipboardData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClipboardData")
+            .field("text", &self.text)
+            .finish()
+    }
 }
 
 impl_event![
@@ -0,0 +1,26 @@
+use dioxus_core::Event;
+
+pub type CustomEvent = Event<CustomData>;
+
+/// A `CustomEvent` dispatched by a web component or some other script on the page.
+///
+/// Dioxus doesn't know the names of custom events ahead of time, so listen for them with a quoted attribute:
+///
+/// ```rust, ignore
+/// sl-input {
+///     "onsl-change": cx.listener(move |evt: CustomEvent| {
+///         let detail: Option<Detail> = evt.detail.as_deref().and_then(|json| serde_json::from_str(json).ok());
+///     }),
+/// }
+/// ```
+///
+/// Custom events are delegated like bubbling events, so only events dispatched with `bubbles: true` are received.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CustomData {
+    /// The `detail` of the event, encoded as JSON
+    ///
+    /// This is `None` if the event has no detail, or if it can't be encoded as JSON.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub detail: Option<String>,
+}
@@ -1,6 +1,8 @@
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+
 use dioxus_core::Event;
 
-use crate::MouseData;
+use crate::{FileEngine, MouseData};
 
 pub type DragEvent = Event<DragData>;
 
@@ -9,10 +11,54 @@ pub type DragEvent = Event<DragData>;
 /// (such as another DOM element). Applications are free to interpret a drag and drop interaction in an
 /// application-specific way.
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct DragData {
     /// Inherit mouse data
     pub mouse: MouseData,
+
+    /// The formats of the data being dragged, like `text/plain` or `text/uri-list`
+    ///
+    /// Files show up as the `Files` format.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub types: Vec<String>,
+
+    /// The data being dragged, keyed by format
+    ///
+    /// Browsers only let you read the data during `dragstart` and `drop`, so this is empty for the other events.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub data: HashMap<String, String>,
+
+    /// The files that were dropped, if any
+    #[cfg_attr(
+        feature = "serialize",
+        serde(default, with = "crate::events::form::serialize_files")
+    )]
+    pub files: Option<Arc<dyn FileEngine>>,
+}
+
+impl PartialEq for DragData {
+    fn eq(&self, other: &Self) -> bool {
+        self.mouse == other.mouse && self.types == other.types && self.data == other.data
+    }
+}
+
+impl Eq for DragData {}
+
+impl Debug for DragData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DragData")
+            .field("mouse", &self.mouse)
+            .field("types", &self.types)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+impl DragData {
+    /// Get the data being dragged in the given format, like `text/plain`
+    pub fn get_data(&self, format: &str) -> Option<&str> {
+        self.data.get(format).map(String::as_str)
+    }
 }
 
 impl_event! {
@@ -1,10 +1,17 @@
-use dioxus_core::Event;
+use dioxus_core::{ElementId, Event};
 
 pub type FocusEvent = Event<FocusData>;
 
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct FocusData {/* DOMEventInner:  Send + SyncTarget relatedTarget */}
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FocusData {
+    /// The other element involved in the focus change: the element losing focus for `focus` and `focusin`, and the
+    /// element gaining focus for `blur` and `focusout`.
+    ///
+    /// This is `None` if focus moved in from (or out to) outside the document, or if the other element isn't one that
+    /// Dioxus assigned an id to.
+    pub related_target: Option<ElementId>,
+}
 
 impl_event! [
     FocusData;
@@ -20,4 +27,10 @@ impl_event! [
 
     /// onblur
     onblur
+
+    /// Like [`onfocusin`], but called in the capture phase before the element gaining focus sees the event
+    onfocusincapture
+
+    /// Like [`onfocusout`], but called in the capture phase before the element losing focus sees the event
+    onfocusoutcapture
 ];
@@ -1,4 +1,7 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+};
 
 use dioxus_core::Event;
 
@@ -6,19 +9,62 @@ pub type FormEvent = Event<FormData>;
 
 /* DOMEvent:  Send + SyncTarget relatedTarget */
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct FormData {
     pub value: String,
 
     pub values: HashMap<String, String>,
 
-    #[cfg_attr(feature = "serialize", serde(skip))]
+    /// Every selected value if the target is a `<select multiple>`, in the order of the options
+    ///
+    /// [`FormData::value`] only holds the first selected value.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub selected: Vec<String>,
+
+    /// Every selected value of each named `<select multiple>` in the form
+    ///
+    /// [`FormData::values`] only holds the first selected value of these selects.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub selected_values: HashMap<String, Vec<String>>,
+
+    /// The value of the target parsed as a number, like the DOM's `valueAsNumber`
+    ///
+    /// This is set for number and range inputs. Date and time inputs give the number of milliseconds since the UNIX
+    /// epoch, or since midnight for time inputs. It is `None` for empty inputs and inputs that aren't numeric.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub value_as_number: Option<f64>,
+
+    /// The markup inside of the target if it is a contenteditable element
+    ///
+    /// [`FormData::value`] only holds the text of contenteditable elements, which loses any formatting.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub html: Option<String>,
+
+    /// The files picked in a file input
+    ///
+    /// When the event is serialized, only the names, sizes and MIME types of the files are sent along, so the files
+    /// can't be read on the other side.
+    #[cfg_attr(feature = "serialize", serde(default, with = "serialize_files"))]
     pub files: Option<std::sync::Arc<dyn FileEngine>>,
 }
 
 impl PartialEq for FormData {
     fn eq(&self, other: &Self) -> bool {
-        self.value == other.value && self.values == other.values
+        self.value == other.value
+            && self.values == other.values
+            && self.selected == other.selected
+            && self.selected_values == other.selected_values
+            && self.value_as_number == other.value_as_number
+            && self.html == other.html
+    }
+}
+
+impl FormData {
+    /// The value of the target as a number, if it is a numeric input that isn't empty
+    ///
+    /// See [`FormData::value_as_number`] for how dates and times are represented.
+    pub fn number(&self) -> Option<f64> {
+        self.value_as_number
     }
 }
 
@@ -27,20 +73,369 @@ impl Debug for FormData {
         f.debug_struct("FormEvent")
             .field("value", &self.value)
             .field("values", &self.values)
+            .field("selected", &self.selected)
+            .field("selected_values", &self.selected_values)
+            .field("value_as_number", &self.value_as_number)
+            .field("html", &self.html)
             .finish()
     }
 }
 
+/// Why a [`FileEngine`] couldn't read a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileEngineError {
+    /// There is no file with this name, or it was moved or deleted since it was picked
+    NotFound(String),
+
+    /// The renderer isn't allowed to read this file
+    PermissionDenied(String),
+
+    /// Reading the file failed part of the way through, or the file changed while it was being read
+    ReadFailed(String),
+}
+
+impl Display for FileEngineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileEngineError::NotFound(file) => write!(f, "File {} was not found", file),
+            FileEngineError::PermissionDenied(file) => {
+                write!(f, "Permission to read file {} was denied", file)
+            }
+            FileEngineError::ReadFailed(file) => write!(f, "Failed to read file {}", file),
+        }
+    }
+}
+
+impl std::error::Error for FileEngineError {}
+
 #[async_trait::async_trait(?Send)]
 pub trait FileEngine {
     // get a list of file names
     fn files(&self) -> Vec<String>;
 
+    // get the size of a file in bytes, so large files can be rejected before they are read
+    fn file_size(&self, _file: &str) -> Option<u64> {
+        None
+    }
+
+    // get the MIME type of a file, like `image/png`, if the renderer knows it
+    fn file_mime(&self, _file: &str) -> Option<String> {
+        None
+    }
+
+    // get the `accept` attribute of the file input the files were picked with, like `image/*,.pdf`
+    fn accept(&self) -> Option<String> {
+        None
+    }
+
     // read a file to bytes
-    async fn read_file(&self, file: &str) -> Option<Vec<u8>>;
+    async fn read_file(&self, file: &str) -> Result<Vec<u8>, FileEngineError>;
 
     // read a file to string
-    async fn read_file_to_string(&self, file: &str) -> Option<String>;
+    async fn read_file_to_string(&self, file: &str) -> Result<String, FileEngineError>;
+
+    // read the bytes from `start` up to `end` of a file, which may be fewer than asked for at the end of the file
+    //
+    // renderers should override this to avoid reading the whole file for every range
+    async fn read_file_range(
+        &self,
+        file: &str,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<u8>, FileEngineError> {
+        let bytes = self.read_file(file).await?;
+        let end = (end.min(bytes.len() as u64)) as usize;
+        let start = (start as usize).min(end);
+        Ok(bytes[start..end].to_vec())
+    }
+
+    // read a file to a `data:` url, which can be used as the `src` of an image to preview it
+    //
+    // files without a known MIME type are encoded as `application/octet-stream`
+    async fn read_file_as_data_url(&self, file: &str) -> Result<String, FileEngineError> {
+        let bytes = self.read_file(file).await?;
+        let mime = self
+            .file_mime(file)
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+        Ok(format!("data:{};base64,{}", mime, encode_base64(&bytes)))
+    }
+
+    // walk the tree of files and directories, falling back to the flat list of files if the renderer can't see directories
+    async fn entries(&self) -> Vec<DirEntry> {
+        self.files()
+            .into_iter()
+            .map(|path| DirEntry {
+                name: path.rsplit('/').next().unwrap_or_default().to_string(),
+                path,
+                children: None,
+            })
+            .collect()
+    }
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = ((chunk[0] as u32) << 16)
+            | ((*chunk.get(1).unwrap_or(&0) as u32) << 8)
+            | (*chunk.get(2).unwrap_or(&0) as u32);
+
+        // Every 3 bytes become 4 characters, and a short chunk at the end is padded with `=`
+        for idx in 0..4 {
+            if idx <= chunk.len() {
+                encoded.push(ALPHABET[((triple >> (18 - 6 * idx)) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+impl dyn FileEngine {
+    /// Check if a file matches the `accept` filter of the input it was picked with, the same way the file picker does.
+    ///
+    /// The filter is a comma separated list of extensions like `.pdf`, MIME types like `image/png`, and wildcard MIME
+    /// types like `image/*`. Every file matches if the input doesn't have a filter. Browsers don't stop users from
+    /// picking other files, so use this to reject them.
+    pub fn matches_accept(&self, file: &str) -> bool {
+        let accept = match self.accept() {
+            Some(accept) if !accept.trim().is_empty() => accept,
+            _ => return true,
+        };
+        let name = file.to_ascii_lowercase();
+        let mime = self.file_mime(file).map(|mime| mime.to_ascii_lowercase());
+
+        accept.split(',').any(|filter| {
+            let filter = filter.trim().to_ascii_lowercase();
+            if filter.starts_with('.') {
+                name.ends_with(&filter)
+            } else if let Some(kind) = filter.strip_suffix("/*") {
+                mime.as_deref()
+                    .and_then(|mime| mime.split('/').next())
+                    .map_or(false, |mime_kind| mime_kind == kind)
+            } else {
+                mime.as_deref() == Some(filter.as_str())
+            }
+        })
+    }
+
+    /// Read a file in chunks of at most `chunk_size` bytes, so large files never have to fit in memory all at once
+    ///
+    /// ```rust, ignore
+    /// let mut chunks = files.read_file_chunked(&name, 1024 * 1024);
+    /// while let Some(chunk) = chunks.next().await {
+    ///     hasher.update(&chunk?);
+    /// }
+    /// ```
+    pub fn read_file_chunked(&self, file: &str, chunk_size: u64) -> FileChunks<'_> {
+        FileChunks {
+            engine: self,
+            file: file.to_string(),
+            offset: 0,
+            chunk_size: chunk_size.max(1),
+            done: false,
+        }
+    }
+
+    /// Read a whole file, calling `on_progress` with the number of bytes read so far and the size of the file after
+    /// every chunk.
+    ///
+    /// The last call always reports the whole file as read, even for empty files. If the renderer doesn't know the
+    /// size of the file, the file is read in one go and that is the only call.
+    ///
+    /// ```rust, ignore
+    /// let bytes = files
+    ///     .read_file_with_progress(&name, |loaded, total| progress.set(loaded as f64 / total.max(1) as f64))
+    ///     .await;
+    /// ```
+    pub async fn read_file_with_progress(
+        &self,
+        file: &str,
+        on_progress: impl Fn(u64, u64),
+    ) -> Result<Vec<u8>, FileEngineError> {
+        const CHUNK_SIZE: u64 = 1024 * 1024;
+
+        let total = match self.file_size(file) {
+            Some(total) => total,
+            None => {
+                let bytes = self.read_file(file).await?;
+                on_progress(bytes.len() as u64, bytes.len() as u64);
+                return Ok(bytes);
+            }
+        };
+
+        let mut bytes = Vec::with_capacity(total as usize);
+        let mut chunks = self.read_file_chunked(file, CHUNK_SIZE);
+        while let Some(chunk) = chunks.next().await {
+            bytes.extend_from_slice(&chunk?);
+            if (bytes.len() as u64) < total {
+                on_progress(bytes.len() as u64, total);
+            }
+        }
+
+        // The file changed while we were reading it
+        if bytes.len() as u64 != total {
+            return Err(FileEngineError::ReadFailed(file.to_string()));
+        }
+
+        on_progress(total, total);
+        Ok(bytes)
+    }
+}
+
+/// The chunks of a file being read with [`FileEngine::read_file_chunked`](trait.FileEngine.html#method.read_file_chunked)
+pub struct FileChunks<'a> {
+    engine: &'a dyn FileEngine,
+    file: String,
+    offset: u64,
+    chunk_size: u64,
+    done: bool,
+}
+
+impl FileChunks<'_> {
+    /// Read the next chunk of the file
+    ///
+    /// This returns `None` once the whole file has been read. If the file can't be read anymore, for example because
+    /// it was deleted or moved while it was being read, this returns the error once and then `None`.
+    pub async fn next(&mut self) -> Option<Result<Vec<u8>, FileEngineError>> {
+        if self.done {
+            return None;
+        }
+
+        let end = self.offset + self.chunk_size;
+        let chunk = self
+            .engine
+            .read_file_range(&self.file, self.offset, end)
+            .await;
+
+        match chunk {
+            // A short chunk means we hit the end of the file
+            Ok(chunk) if !chunk.is_empty() => {
+                self.offset += chunk.len() as u64;
+                self.done = (chunk.len() as u64) < self.chunk_size;
+                Some(Ok(chunk))
+            }
+            Ok(_) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+
+    /// How many bytes have been read so far
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+#[cfg(feature = "serialize")]
+pub(crate) mod serialize_files {
+    //! Serialize the files of an event as their metadata, since their contents can be huge and are often never read
+
+    use super::{FileEngine, FileEngineError};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::sync::Arc;
+
+    #[derive(Serialize, Deserialize)]
+    struct SerializedFile {
+        name: String,
+        size: Option<u64>,
+        mime: Option<String>,
+    }
+
+    pub(crate) fn serialize<S: Serializer>(
+        files: &Option<Arc<dyn FileEngine>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let files = files.as_ref().map(|engine| {
+            engine
+                .files()
+                .into_iter()
+                .map(|name| SerializedFile {
+                    size: engine.file_size(&name),
+                    mime: engine.file_mime(&name),
+                    name,
+                })
+                .collect::<Vec<_>>()
+        });
+
+        files.serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Arc<dyn FileEngine>>, D::Error> {
+        let files = Option::<Vec<SerializedFile>>::deserialize(deserializer)?;
+        Ok(files.map(|files| Arc::new(SerializedFileEngine { files }) as Arc<dyn FileEngine>))
+    }
+
+    /// The files of an event that was serialized somewhere else. Only their metadata was sent, so they can't be read.
+    struct SerializedFileEngine {
+        files: Vec<SerializedFile>,
+    }
+
+    impl SerializedFileEngine {
+        fn get(&self, file: &str) -> Option<&SerializedFile> {
+            self.files.iter().find(|f| f.name == file)
+        }
+
+        fn unreadable(&self, file: &str) -> FileEngineError {
+            match self.get(file) {
+                Some(_) => FileEngineError::PermissionDenied(file.to_string()),
+                None => FileEngineError::NotFound(file.to_string()),
+            }
+        }
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl FileEngine for SerializedFileEngine {
+        fn files(&self) -> Vec<String> {
+            self.files.iter().map(|f| f.name.clone()).collect()
+        }
+
+        fn file_size(&self, file: &str) -> Option<u64> {
+            self.get(file)?.size
+        }
+
+        fn file_mime(&self, file: &str) -> Option<String> {
+            self.get(file)?.mime.clone()
+        }
+
+        async fn read_file(&self, file: &str) -> Result<Vec<u8>, FileEngineError> {
+            Err(self.unreadable(file))
+        }
+
+        async fn read_file_to_string(&self, file: &str) -> Result<String, FileEngineError> {
+            Err(self.unreadable(file))
+        }
+    }
+}
+
+/// A file or directory in a tree of files, like one dropped onto the page
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DirEntry {
+    /// The name of the file or directory
+    pub name: String,
+
+    /// The full path of the file or directory, which can be passed to [`FileEngine::read_file`] for files
+    pub path: String,
+
+    /// The entries inside of this directory, or `None` if this is a file
+    pub children: Option<Vec<DirEntry>>,
+}
+
+impl DirEntry {
+    /// Check if this entry is a directory
+    pub fn is_dir(&self) -> bool {
+        self.children.is_some()
+    }
 }
 
 impl_event! {
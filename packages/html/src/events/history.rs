@@ -0,0 +1,37 @@
+use dioxus_core::Event;
+
+pub type HistoryEvent = Event<HistoryData>;
+
+/// A navigation through the session history, like the back button or a change to the hash of the url.
+///
+/// These events are dispatched on the window, so every element listening for them receives every navigation.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HistoryData {
+    /// The path of the url after the navigation, like `/blog/post`
+    pub pathname: String,
+
+    /// The hash of the url after the navigation, including the leading `#`, or an empty string if there is none
+    pub hash: String,
+
+    /// The state passed to `pushState` or `replaceState` for this history entry, encoded as JSON
+    ///
+    /// This is `None` if the entry has no state.
+    pub state: Option<String>,
+
+    /// The full url before a `hashchange`, or `None` for a `popstate`
+    pub old_url: Option<String>,
+
+    /// The full url after a `hashchange`, or `None` for a `popstate`
+    pub new_url: Option<String>,
+}
+
+impl_event! {
+    HistoryData;
+
+    /// onpopstate
+    onpopstate
+
+    /// onhashchange
+    onhashchange
+}
@@ -53,6 +53,10 @@ pub struct KeyboardData {
 
     #[deprecated(since = "0.3.0", note = "use code() or key() instead")]
     pub which: usize,
+
+    /// Indicate if the event was fired while an IME composition is in progress
+    #[cfg_attr(feature = "serialize", serde(default))]
+    is_composing: bool,
 }
 
 impl_event! {
@@ -66,6 +70,9 @@ impl_event! {
 
     /// onkeyup
     onkeyup
+
+    /// Like [`onkeydown`], but called in the capture phase before the event reaches the focused element
+    onkeydowncapture
 }
 
 impl KeyboardData {
@@ -96,9 +103,16 @@ impl KeyboardData {
                 .legacy_charcode()
                 .try_into()
                 .expect("could not convert charcode to usize"),
+            is_composing: false,
         }
     }
 
+    /// Mark whether the event was fired while an IME composition is in progress
+    pub fn with_composing(mut self, is_composing: bool) -> Self {
+        self.is_composing = is_composing;
+        self
+    }
+
     /// The value of the key pressed by the user, taking into consideration the state of modifier keys such as Shift as well as the keyboard locale and layout.
     pub fn key(&self) -> Key {
         #[allow(deprecated)]
@@ -144,6 +158,13 @@ impl KeyboardData {
         #[allow(deprecated)]
         self.repeat
     }
+
+    /// `true` iff the event was fired while an IME composition is in progress.
+    ///
+    /// Text inputs should usually ignore these events, since the user hasn't committed to the text yet.
+    pub fn is_composing(&self) -> bool {
+        self.is_composing
+    }
 }
 
 impl Debug for KeyboardData {
@@ -154,6 +175,7 @@ impl Debug for KeyboardData {
             .field("modifiers", &self.modifiers())
             .field("location", &self.location())
             .field("is_auto_repeating", &self.is_auto_repeating())
+            .field("is_composing", &self.is_composing())
             .finish()
     }
 }
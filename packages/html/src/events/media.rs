@@ -1,9 +1,46 @@
 use dioxus_core::Event;
 
 pub type MediaEvent = Event<MediaData>;
+
+/// The playback state of the media element an event was fired on, read when the event was dispatched
+///
+/// Events like `load` and `error` share this type but can fire on any element, so missing fields use their defaults.
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct MediaData {}
+#[cfg_attr(feature = "serialize", serde(default))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaData {
+    /// The current playback position, in seconds
+    pub current_time: f64,
+
+    /// The length of the media in seconds, or 0 if it is unknown or the media is a live stream
+    pub duration: f64,
+
+    /// The volume, from 0 (silent) to 1 (loudest)
+    pub volume: f64,
+
+    /// Whether the audio is muted
+    pub muted: bool,
+
+    /// Whether playback is paused
+    pub paused: bool,
+
+    /// The rate the media is played at, where 1 is normal speed
+    pub playback_rate: f64,
+}
+
+impl Default for MediaData {
+    /// The state of a media element that hasn't loaded anything yet
+    fn default() -> Self {
+        Self {
+            current_time: 0.0,
+            duration: 0.0,
+            volume: 1.0,
+            muted: false,
+            paused: true,
+            playback_rate: 1.0,
+        }
+    }
+}
 
 impl_event! [
     MediaData;
@@ -0,0 +1,30 @@
+use dioxus_core::{ElementId, Event};
+
+pub type MountedEvent = Event<MountedData>;
+
+/// Data for the `mounted` event, which is fired once after an element is added to the page.
+///
+/// The mounted event isn't a browser event. The renderer fires it after it has created the element, so this is the
+/// place to focus an input or measure an element.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MountedData {
+    /// The id of the element that was mounted
+    pub id: ElementId,
+}
+
+impl MountedData {
+    /// Create the data for an element that was just mounted
+    pub fn new(id: ElementId) -> Self {
+        Self { id }
+    }
+}
+
+impl_event! {
+    MountedData;
+
+    /// onmounted
+    ///
+    /// Called once the element has been added to the page
+    onmounted
+}
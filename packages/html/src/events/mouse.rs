@@ -147,6 +147,16 @@ impl_event! {
 
     /// onmouseup
     onmouseup
+
+    /// Like [`onclick`], but called in the capture phase: before the click reaches the target, starting at the
+    /// outermost ancestor.
+    ///
+    /// This is handy for "click outside to close" menus, which need to see every click before the element that was
+    /// clicked handles it.
+    onclickcapture
+
+    /// Like [`onmousedown`], but called in the capture phase before the event reaches the target
+    onmousedowncapture
 }
 
 impl MouseData {
@@ -266,6 +276,13 @@ impl MouseData {
         #[allow(deprecated)]
         Some(MouseButton::from_web_code(self.button))
     }
+
+    /// Check if the primary button (typically the left button) triggered the event
+    ///
+    /// Like [`MouseData::trigger_button`], this is only reliable for events caused by pressing or releasing a button.
+    pub fn is_primary_button(&self) -> bool {
+        self.trigger_button() == Some(MouseButton::Primary)
+    }
 }
 
 impl Debug for MouseData {
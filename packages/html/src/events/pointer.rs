@@ -1,4 +1,7 @@
+use crate::geometry::{ClientPoint, PagePoint, ScreenPoint};
+use crate::input_data::MouseButton;
 use dioxus_core::Event;
+use keyboard_types::Modifiers;
 
 pub type PointerEvent = Event<PointerData>;
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
@@ -28,6 +31,88 @@ pub struct PointerData {
     pub pointer_type: String,
     pub is_primary: bool,
     // pub get_modifier_state: bool,
+    /// Every position the pointer moved through since the last event, oldest first
+    ///
+    /// Browsers merge pointer moves that happen faster than they dispatch events, so a fast stroke can skip over a lot
+    /// of positions. Drawing through these instead of just the position of the event keeps lines smooth. If the
+    /// browser doesn't report them, this only contains the event itself.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub coalesced: Vec<PointerSample>,
+}
+
+/// A single position a pointer was at, part of a [`PointerData`] that the browser merged several moves into
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PointerSample {
+    pub client_x: i32,
+    pub client_y: i32,
+    pub page_x: i32,
+    pub page_y: i32,
+    pub screen_x: i32,
+    pub screen_y: i32,
+    pub pressure: f32,
+    /// When the pointer was at this position, in milliseconds since the page was loaded
+    pub time_stamp: f64,
+}
+
+impl PointerSample {
+    /// The sample's coordinates relative to the application's viewport
+    pub fn client_coordinates(&self) -> ClientPoint {
+        ClientPoint::new(self.client_x.into(), self.client_y.into())
+    }
+
+    /// The sample's coordinates relative to the entire document, including any portion that isn't currently visible
+    pub fn page_coordinates(&self) -> PagePoint {
+        PagePoint::new(self.page_x.into(), self.page_y.into())
+    }
+
+    /// The sample's coordinates relative to the entire screen
+    pub fn screen_coordinates(&self) -> ScreenPoint {
+        ScreenPoint::new(self.screen_x.into(), self.screen_y.into())
+    }
+}
+
+impl PointerData {
+    /// The event's coordinates relative to the application's viewport
+    pub fn client_coordinates(&self) -> ClientPoint {
+        ClientPoint::new(self.client_x.into(), self.client_y.into())
+    }
+
+    /// The event's coordinates relative to the entire document, including any portion that isn't currently visible
+    pub fn page_coordinates(&self) -> PagePoint {
+        PagePoint::new(self.page_x.into(), self.page_y.into())
+    }
+
+    /// The event's coordinates relative to the entire screen
+    pub fn screen_coordinates(&self) -> ScreenPoint {
+        ScreenPoint::new(self.screen_x.into(), self.screen_y.into())
+    }
+
+    /// The set of modifier keys which were pressed when the event occurred
+    pub fn modifiers(&self) -> Modifiers {
+        let mut modifiers = Modifiers::empty();
+
+        if self.alt_key {
+            modifiers.insert(Modifiers::ALT);
+        }
+        if self.ctrl_key {
+            modifiers.insert(Modifiers::CONTROL);
+        }
+        if self.meta_key {
+            modifiers.insert(Modifiers::META);
+        }
+        if self.shift_key {
+            modifiers.insert(Modifiers::SHIFT);
+        }
+
+        modifiers
+    }
+
+    /// The button that triggered the event, or `None` if the event wasn't caused by a button changing, like a move
+    pub fn trigger_button(&self) -> Option<MouseButton> {
+        // Pointer events report -1 when no button changed
+        (self.button >= 0).then(|| MouseButton::from_web_code(self.button))
+    }
 }
 
 impl_event![
@@ -61,4 +146,12 @@ impl_event![
 
     /// pointerout
     onpointerout
+
+    /// pointerrawupdate
+    ///
+    /// Fires for every pointer sample the browser receives, which can be much more often than pointermove
+    onpointerrawupdate
+
+    /// Like [`onpointerdown`], but called in the capture phase before the event reaches the target
+    onpointerdowncapture
 ];
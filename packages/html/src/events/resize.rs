@@ -0,0 +1,34 @@
+use dioxus_core::Event;
+
+pub type ResizeEvent = Event<ResizeData>;
+
+/// Data for the `resize` event, which is fired when the size of an element's content changes.
+///
+/// The resize event isn't a browser event, and it isn't the `resize` event of the window. The renderer watches every
+/// element with an `onresize` listener with a
+/// [`ResizeObserver`](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver), which fires once with the
+/// size the element starts out with and then every time it changes.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ResizeData {
+    /// The left edge of the content, relative to the element's padding
+    pub x: f64,
+
+    /// The top edge of the content, relative to the element's padding
+    pub y: f64,
+
+    /// The width of the content, not including padding or borders
+    pub width: f64,
+
+    /// The height of the content, not including padding or borders
+    pub height: f64,
+}
+
+impl_event! {
+    ResizeData;
+
+    /// onresize
+    ///
+    /// Called when the size of the element's content changes
+    onresize
+}
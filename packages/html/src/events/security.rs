@@ -0,0 +1,26 @@
+use dioxus_core::Event;
+
+pub type SecurityPolicyViolationEvent = Event<SecurityPolicyViolationData>;
+
+/// A Content Security Policy violation reported by the browser.
+///
+/// This event is dispatched on the document, so every element listening for it receives every violation.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SecurityPolicyViolationData {
+    pub document_uri: String,
+    pub blocked_uri: String,
+    pub violated_directive: String,
+    pub effective_directive: String,
+    pub original_policy: String,
+    pub source_file: String,
+    pub line_number: u32,
+    pub column_number: u32,
+}
+
+impl_event! {
+    SecurityPolicyViolationData;
+
+    /// onsecuritypolicyviolation
+    onsecuritypolicyviolation
+}
@@ -2,8 +2,24 @@ use dioxus_core::Event;
 
 pub type SelectionEvent = Event<SelectionData>;
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SelectionData {}
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SelectionData {
+    /// The index the selection starts at, for inputs and text areas
+    ///
+    /// Like the DOM, indices count UTF-16 code units, not bytes or chars.
+    pub start: Option<u32>,
+
+    /// The index the selection ends at, for inputs and text areas
+    ///
+    /// If nothing is selected this is the same as `start`, which is the position of the caret.
+    pub end: Option<u32>,
+
+    /// The text that is selected
+    ///
+    /// For inputs and text areas this is the value between `start` and `end`, otherwise it is the text of the
+    /// document selection, which covers contenteditable elements.
+    pub text: Option<String>,
+}
 
 impl_event! [
     SelectionData;
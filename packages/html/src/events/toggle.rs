@@ -1,13 +1,34 @@
 use dioxus_core::Event;
 
 pub type ToggleEvent = Event<ToggleData>;
+
+/// Data for the `toggle` and `beforetoggle` events fired by `<details>` elements and popovers.
+///
+/// The states are either `"open"` or `"closed"`. They may be empty if the browser does not report them.
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ToggleData {}
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ToggleData {
+    /// The state of the element before it was toggled
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub old_state: String,
+
+    /// The state of the element after it is toggled
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub new_state: String,
+
+    /// Whether the element is open after it is toggled
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub open: bool,
+}
 
 impl_event! {
     ToggleData;
 
     /// ontoggle
     ontoggle
+
+    /// onbeforetoggle
+    ///
+    /// This event is cancelable: preventing its default action stops a popover from opening.
+    onbeforetoggle
 }
@@ -1,17 +1,68 @@
+use crate::geometry::{ClientPoint, PagePoint, ScreenPoint};
 use dioxus_core::Event;
 
 pub type TouchEvent = Event<TouchData>;
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TouchData {
     pub alt_key: bool,
     pub ctrl_key: bool,
     pub meta_key: bool,
     pub shift_key: bool,
     // get_modifier_state: bool,
-    // changedTouches: DOMTouchList,
-    // targetTouches: DOMTouchList,
-    // touches: DOMTouchList,
+    /// Every touch that is currently on the surface
+    ///
+    /// Touches that were just lifted aren't on the surface anymore, so on `touchend` and `touchcancel` they are only in
+    /// [`TouchData::changed_touches`].
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub touches: Vec<TouchPoint>,
+
+    /// The touches that started, moved or ended in this event
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub changed_touches: Vec<TouchPoint>,
+
+    /// The touches that are on the surface and started on the element the event was dispatched to
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub target_touches: Vec<TouchPoint>,
+}
+
+/// A single point of contact with a touch surface, like a finger or a stylus
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TouchPoint {
+    /// An id that stays the same for as long as the touch is on the surface, to follow it between events
+    pub identifier: i32,
+    pub client_x: f64,
+    pub client_y: f64,
+    pub page_x: f64,
+    pub page_y: f64,
+    pub screen_x: f64,
+    pub screen_y: f64,
+    /// The radius of the ellipse that covers the contact area along the x axis, in css pixels
+    pub radius_x: f64,
+    /// The radius of the ellipse that covers the contact area along the y axis, in css pixels
+    pub radius_y: f64,
+    /// How many degrees the contact ellipse is rotated clockwise
+    pub rotation_angle: f32,
+    /// How hard the touch presses on the surface, from 0 to 1, or 0 if the device can't tell
+    pub force: f32,
+}
+
+impl TouchPoint {
+    /// The touch's coordinates relative to the application's viewport
+    pub fn client_coordinates(&self) -> ClientPoint {
+        ClientPoint::new(self.client_x, self.client_y)
+    }
+
+    /// The touch's coordinates relative to the entire document, including any portion that isn't currently visible
+    pub fn page_coordinates(&self) -> PagePoint {
+        PagePoint::new(self.page_x, self.page_y)
+    }
+
+    /// The touch's coordinates relative to the entire screen
+    pub fn screen_coordinates(&self) -> ScreenPoint {
+        ScreenPoint::new(self.screen_x, self.screen_y)
+    }
 }
 
 impl_event! {
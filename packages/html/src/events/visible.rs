@@ -0,0 +1,27 @@
+use dioxus_core::Event;
+
+pub type VisibleEvent = Event<VisibleData>;
+
+/// Data for the `visible` event, which is fired when an element scrolls into or out of the viewport.
+///
+/// The visible event isn't a browser event. The renderer watches every element with an `onvisible` listener with an
+/// [`IntersectionObserver`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver), which fires once
+/// when the element starts being watched and then every time it crosses the edge of the viewport.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VisibleData {
+    /// Whether any part of the element is in the viewport
+    pub is_intersecting: bool,
+
+    /// How much of the element is in the viewport, from `0.0` to `1.0`
+    pub intersection_ratio: f64,
+}
+
+impl_event! {
+    VisibleData;
+
+    /// onvisible
+    ///
+    /// Called when the element scrolls into or out of the viewport
+    onvisible
+}
@@ -67,6 +67,13 @@ impl WheelData {
             _ => panic!("Invalid delta mode, {:?}", self.delta_mode),
         }
     }
+
+    /// The amount of wheel movement in pixels, whatever unit the browser reported it in
+    ///
+    /// See [`WheelDelta::to_pixels`] for how lines and pages are converted.
+    pub fn delta_pixels(&self) -> PixelsVector {
+        self.delta().to_pixels()
+    }
 }
 
 impl Debug for WheelData {
@@ -14,6 +14,8 @@ pub type ScreenPoint = Point2D<f64, ScreenSpace>;
 pub struct ClientSpace;
 /// A point in ClientSpace
 pub type ClientPoint = Point2D<f64, ClientSpace>;
+/// A rectangle in ClientSpace
+pub type ClientRect = Rect<f64, ClientSpace>;
 
 /// Coordinate space relative to an element
 pub struct ElementSpace;
@@ -44,6 +46,14 @@ pub struct Pages;
 /// A vector expressed in Pages
 pub type PagesVector = Vector3D<f64, Pages>;
 
+/// The height of a line in pixels, used by [`WheelDelta::to_pixels`]
+///
+/// This matches the default font size of most browsers.
+pub const LINE_HEIGHT_PIXELS: f64 = 16.0;
+
+/// The size of a page in pixels, used by [`WheelDelta::to_pixels`]
+pub const PAGE_SIZE_PIXELS: f64 = 800.0;
+
 /// A vector representing the amount the mouse wheel was moved
 ///
 /// This may be expressed in Pixels, Lines or Pages
@@ -93,6 +103,25 @@ impl WheelDelta {
             WheelDelta::Pages(v) => v.cast_unit(),
         }
     }
+
+    /// The movement in pixels, converting lines and pages with [`LINE_HEIGHT_PIXELS`] and [`PAGE_SIZE_PIXELS`]
+    ///
+    /// Browsers report wheel movement in different units, so this gives consistent deltas when you only care about
+    /// the rough distance scrolled, like for zoom controls.
+    pub fn to_pixels(&self) -> PixelsVector {
+        self.to_pixels_with(LINE_HEIGHT_PIXELS, PAGE_SIZE_PIXELS)
+    }
+
+    /// The movement in pixels, converting lines and pages with the given sizes in pixels
+    ///
+    /// Use this if you know the actual line height or page size of the element being scrolled.
+    pub fn to_pixels_with(&self, line_height: f64, page_size: f64) -> PixelsVector {
+        match self {
+            WheelDelta::Pixels(v) => *v,
+            WheelDelta::Lines(v) => v.cast_unit::<Pixels>() * line_height,
+            WheelDelta::Pages(v) => v.cast_unit::<Pixels>() * page_size,
+        }
+    }
 }
 
 /// Coordinates of a point in the app's interface
@@ -82,6 +82,9 @@ trait_methods! {
 
     /// Prevent the default action for this element.
     ///
+    /// `touchstart`, `touchmove` and `wheel` are listened to passively so they never hold up scrolling. Preventing
+    /// their default action here, like `prevent_default: "onwheel"`, makes them non-passive again.
+    ///
     /// For more information, see the MDN docs:
     /// <https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault>
     prevent_default: "dioxus-prevent-default";
@@ -209,6 +212,14 @@ trait_methods! {
     /// HTML from code is risky because it’s easy to inadvertently expose your users to a cross-site scripting (XSS)
     /// attack. So, you can set HTML directly from Dioxus, but you have to type out dangerous_inner_html to remind
     /// yourself that it’s dangerous
+    ///
+    /// Setting it replaces every child of the element with the parsed HTML, so updating it doesn't pile up old
+    /// content. Dioxus doesn't diff the nodes it creates, so don't give the element children in rsx as well, and
+    /// sanitize any HTML that doesn't come from you.
+    ///
+    /// ```rust, ignore
+    /// div { dangerous_inner_html: "{sanitized_markdown}" }
+    /// ```
     dangerous_inner_html;
 
     // This macro creates an explicit method call for each of the style attributes.
@@ -1593,6 +1604,9 @@ trait_methods! {
 
     /// Prevent the default action for this element.
     ///
+    /// `touchstart`, `touchmove` and `wheel` are listened to passively so they never hold up scrolling. Preventing
+    /// their default action here, like `prevent_default: "onwheel"`, makes them non-passive again.
+    ///
     /// For more information, see the MDN docs:
     /// <https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault>
     prevent_default: "dioxus-prevent-default";
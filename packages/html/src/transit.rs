@@ -75,6 +75,7 @@ fn fun_name(
 
         // Form
         "change" | "input" | "invalid" | "reset" | "submit" => Form(de(data)?),
+        "beforeinput" => BeforeInput(de(data)?),
 
         // Drag
         "drag" | "dragend" | "dragenter" | "dragexit" | "dragleave" | "dragover" | "dragstart"
@@ -83,7 +84,7 @@ fn fun_name(
         // Pointer
         "pointerlockchange" | "pointerlockerror" | "pointerdown" | "pointermove" | "pointerup"
         | "pointerover" | "pointerout" | "pointerenter" | "pointerleave" | "gotpointercapture"
-        | "lostpointercapture" => Pointer(de(data)?),
+        | "lostpointercapture" | "pointerrawupdate" => Pointer(de(data)?),
 
         // Selection
         "selectstart" | "selectionchange" | "select" => Selection(de(data)?),
@@ -111,16 +112,27 @@ fn fun_name(
         "transitionend" => Transition(de(data)?),
 
         // Toggle
-        "toggle" => Toggle(de(data)?),
+        "toggle" | "beforetoggle" => Toggle(de(data)?),
+
+        // Security
+        "securitypolicyviolation" => SecurityPolicyViolation(de(data)?),
+
+        // History
+        "popstate" | "hashchange" => History(de(data)?),
+
+        // Mounted
+        "mounted" => Mounted(de(data)?),
+
+        // Visible
+        "visible" => Visible(de(data)?),
+
+        // Resize
+        "resize" => Resize(de(data)?),
 
         // ImageData => "load" | "error";
-        // OtherData => "abort" | "afterprint" | "beforeprint" | "beforeunload" | "hashchange" | "languagechange" | "message" | "offline" | "online" | "pagehide" | "pageshow" | "popstate" | "rejectionhandled" | "storage" | "unhandledrejection" | "unload" | "userproximity" | "vrdisplayactivate" | "vrdisplayblur" | "vrdisplayconnect" | "vrdisplaydeactivate" | "vrdisplaydisconnect" | "vrdisplayfocus" | "vrdisplaypointerrestricted" | "vrdisplaypointerunrestricted" | "vrdisplaypresentchange";
-        other => {
-            return Err(serde_value::DeserializerError::UnknownVariant(
-                other.to_string(),
-                &[],
-            ))
-        }
+        // OtherData => "abort" | "afterprint" | "beforeprint" | "beforeunload" | "languagechange" | "message" | "offline" | "online" | "pagehide" | "pageshow" | "rejectionhandled" | "storage" | "unhandledrejection" | "unload" | "userproximity" | "vrdisplayactivate" | "vrdisplayblur" | "vrdisplayconnect" | "vrdisplaydeactivate" | "vrdisplaydisconnect" | "vrdisplayfocus" | "vrdisplaypointerrestricted" | "vrdisplaypointerunrestricted" | "vrdisplaypresentchange";
+        // Anything else was dispatched by a script on the page
+        _ => Custom(de(data)?),
     };
 
     Ok(data)
@@ -141,6 +153,7 @@ pub enum EventData {
     Keyboard(KeyboardData),
     Focus(FocusData),
     Form(FormData),
+    BeforeInput(BeforeInputData),
     Drag(DragData),
     Pointer(PointerData),
     Selection(SelectionData),
@@ -151,6 +164,12 @@ pub enum EventData {
     Animation(AnimationData),
     Transition(TransitionData),
     Toggle(ToggleData),
+    SecurityPolicyViolation(SecurityPolicyViolationData),
+    History(HistoryData),
+    Mounted(MountedData),
+    Visible(VisibleData),
+    Resize(ResizeData),
+    Custom(CustomData),
 }
 
 impl EventData {
@@ -162,6 +181,7 @@ impl EventData {
             EventData::Keyboard(data) => Rc::new(data) as Rc<dyn Any>,
             EventData::Focus(data) => Rc::new(data) as Rc<dyn Any>,
             EventData::Form(data) => Rc::new(data) as Rc<dyn Any>,
+            EventData::BeforeInput(data) => Rc::new(data) as Rc<dyn Any>,
             EventData::Drag(data) => Rc::new(data) as Rc<dyn Any>,
             EventData::Pointer(data) => Rc::new(data) as Rc<dyn Any>,
             EventData::Selection(data) => Rc::new(data) as Rc<dyn Any>,
@@ -172,6 +192,12 @@ impl EventData {
             EventData::Animation(data) => Rc::new(data) as Rc<dyn Any>,
             EventData::Transition(data) => Rc::new(data) as Rc<dyn Any>,
             EventData::Toggle(data) => Rc::new(data) as Rc<dyn Any>,
+            EventData::SecurityPolicyViolation(data) => Rc::new(data) as Rc<dyn Any>,
+            EventData::History(data) => Rc::new(data) as Rc<dyn Any>,
+            EventData::Mounted(data) => Rc::new(data) as Rc<dyn Any>,
+            EventData::Visible(data) => Rc::new(data) as Rc<dyn Any>,
+            EventData::Resize(data) => Rc::new(data) as Rc<dyn Any>,
+            EventData::Custom(data) => Rc::new(data) as Rc<dyn Any>,
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::events::{
-    AnimationData, CompositionData, KeyboardData, MouseData, PointerData, TouchData,
-    TransitionData, WheelData,
+    AnimationData, CompositionData, KeyboardData, MouseData, PointerData, PointerSample,
+    SecurityPolicyViolationData, TouchData, TouchPoint, TransitionData, WheelData,
 };
 use crate::geometry::{ClientPoint, Coordinates, ElementPoint, PagePoint, ScreenPoint};
 use crate::input_data::{decode_key_location, decode_mouse_button_set, MouseButton};
@@ -10,8 +10,8 @@ use std::convert::TryInto;
 use std::str::FromStr;
 use wasm_bindgen::JsCast;
 use web_sys::{
-    AnimationEvent, CompositionEvent, Event, KeyboardEvent, MouseEvent, PointerEvent, TouchEvent,
-    TransitionEvent, WheelEvent,
+    AnimationEvent, CompositionEvent, Event, KeyboardEvent, MouseEvent, PointerEvent,
+    SecurityPolicyViolationEvent, Touch, TouchEvent, TouchList, TransitionEvent, WheelEvent,
 };
 
 macro_rules! uncheck_convert {
@@ -47,6 +47,7 @@ uncheck_convert![
     WheelEvent       => WheelData,
     AnimationEvent   => AnimationData,
     TransitionEvent  => TransitionData,
+    SecurityPolicyViolationEvent => SecurityPolicyViolationData,
 ];
 
 impl From<&CompositionEvent> for CompositionData {
@@ -85,6 +86,7 @@ impl From<&KeyboardEvent> for KeyboardData {
             e.repeat(),
             modifiers,
         )
+        .with_composing(e.is_composing())
     }
 }
 
@@ -123,6 +125,9 @@ impl From<&MouseEvent> for DragData {
     fn from(value: &MouseEvent) -> Self {
         Self {
             mouse: MouseData::from(value),
+            types: Vec::new(),
+            data: Default::default(),
+            files: None,
         }
     }
 }
@@ -134,10 +139,38 @@ impl From<&TouchEvent> for TouchData {
             ctrl_key: e.ctrl_key(),
             meta_key: e.meta_key(),
             shift_key: e.shift_key(),
+            touches: touch_points(&e.touches()),
+            changed_touches: touch_points(&e.changed_touches()),
+            target_touches: touch_points(&e.target_touches()),
         }
     }
 }
 
+impl From<&Touch> for TouchPoint {
+    fn from(t: &Touch) -> Self {
+        Self {
+            identifier: t.identifier(),
+            client_x: t.client_x().into(),
+            client_y: t.client_y().into(),
+            page_x: t.page_x().into(),
+            page_y: t.page_y().into(),
+            screen_x: t.screen_x().into(),
+            screen_y: t.screen_y().into(),
+            radius_x: t.radius_x().into(),
+            radius_y: t.radius_y().into(),
+            rotation_angle: t.rotation_angle(),
+            force: t.force(),
+        }
+    }
+}
+
+fn touch_points(list: &TouchList) -> Vec<TouchPoint> {
+    (0..list.length())
+        .filter_map(|index| list.get(index))
+        .map(|touch| TouchPoint::from(&touch))
+        .collect()
+}
+
 impl From<&PointerEvent> for PointerData {
     fn from(e: &PointerEvent) -> Self {
         Self {
@@ -164,10 +197,47 @@ impl From<&PointerEvent> for PointerData {
             pointer_type: e.pointer_type(),
             is_primary: e.is_primary(),
             // get_modifier_state: evt.get_modifier_state(),
+            coalesced: coalesced_samples(e),
         }
     }
 }
 
+impl From<&PointerEvent> for PointerSample {
+    fn from(e: &PointerEvent) -> Self {
+        Self {
+            client_x: e.client_x(),
+            client_y: e.client_y(),
+            page_x: e.page_x(),
+            page_y: e.page_y(),
+            screen_x: e.screen_x(),
+            screen_y: e.screen_y(),
+            pressure: e.pressure(),
+            time_stamp: e.time_stamp(),
+        }
+    }
+}
+
+fn coalesced_samples(e: &PointerEvent) -> Vec<PointerSample> {
+    // Calling getCoalescedEvents throws in browsers that don't have it
+    let supported = js_sys::Reflect::has(e, &"getCoalescedEvents".into()).unwrap_or(false);
+
+    let samples: Vec<PointerSample> = if supported {
+        e.get_coalesced_events()
+            .iter()
+            .filter_map(|event| event.dyn_into::<PointerEvent>().ok())
+            .map(|event| PointerSample::from(&event))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    if samples.is_empty() {
+        vec![e.into()]
+    } else {
+        samples
+    }
+}
+
 impl From<&WheelEvent> for WheelData {
     fn from(e: &WheelEvent) -> Self {
         WheelData::from_web_attributes(e.delta_mode(), e.delta_x(), e.delta_y(), e.delta_z())
@@ -193,3 +263,18 @@ impl From<&TransitionEvent> for TransitionData {
         }
     }
 }
+
+impl From<&SecurityPolicyViolationEvent> for SecurityPolicyViolationData {
+    fn from(e: &SecurityPolicyViolationEvent) -> Self {
+        Self {
+            document_uri: e.document_uri(),
+            blocked_uri: e.blocked_uri(),
+            violated_directive: e.violated_directive(),
+            effective_directive: e.effective_directive(),
+            original_policy: e.original_policy(),
+            source_file: e.source_file(),
+            line_number: e.line_number(),
+            column_number: e.column_number(),
+        }
+    }
+}
@@ -4,45 +4,72 @@ class ListenerMap {
     this.global = {};
     // non bubbling events listen at the element the listener was created at
     this.local = {};
+    // document and window level events listen at their target, keyed by event name and element id
+    this.outside = {};
     this.root = root;
   }
 
   create(event_name, element, handler, bubbles) {
+    if (event_name === "click" && needs_click_patch) {
+      element.addEventListener("click", noop);
+    }
+    const passive = is_scroll_blocking(event_name) && !prevents_default(element, event_name);
     if (bubbles) {
       if (this.global[event_name] === undefined) {
         this.global[event_name] = {};
         this.global[event_name].active = 1;
         this.global[event_name].callback = handler;
-        this.root.addEventListener(event_name, handler);
+        this.global[event_name].passive = passive;
+        this.root.addEventListener(event_name, handler, { passive });
       } else {
         this.global[event_name].active++;
+        if (!passive) {
+          this.preventDefault(element, event_name);
+        }
       }
     }
     else {
+      // an element can have more than one listener for the same event, so only attach the dom listener once
       const id = element.getAttribute("data-dioxus-id");
       if (!this.local[id]) {
         this.local[id] = {};
       }
-      this.local[id][event_name] = handler;
-      element.addEventListener(event_name, handler);
+      if (this.local[id][event_name] === undefined) {
+        this.local[id][event_name] = { active: 1, callback: handler };
+        element.addEventListener(event_name, handler, { passive });
+      } else {
+        this.local[id][event_name].active++;
+      }
     }
   }
 
   remove(element, event_name, bubbles) {
     if (bubbles) {
-      this.global[event_name].active--;
-      if (this.global[event_name].active === 0) {
-        this.root.removeEventListener(event_name, this.global[event_name].callback);
+      const listener = this.global[event_name];
+      if (listener === undefined) {
+        return;
+      }
+      listener.active--;
+      if (listener.active === 0) {
+        this.root.removeEventListener(event_name, listener.callback);
         delete this.global[event_name];
       }
     }
     else {
+      // only detach the dom listener once the last listener for this event on the element is gone
       const id = element.getAttribute("data-dioxus-id");
-      delete this.local[id][event_name];
-      if (this.local[id].length === 0) {
-        delete this.local[id];
+      const listener = this.local[id] && this.local[id][event_name];
+      if (listener === undefined) {
+        return;
+      }
+      listener.active--;
+      if (listener.active === 0) {
+        element.removeEventListener(event_name, listener.callback);
+        delete this.local[id][event_name];
+        if (Object.keys(this.local[id]).length === 0) {
+          delete this.local[id];
+        }
       }
-      element.removeEventListener(event_name, handler);
     }
   }
 
@@ -50,6 +77,126 @@ class ListenerMap {
     const id = element.getAttribute("data-dioxus-id");
     delete this.local[id];
   }
+
+  // an element wants to prevent the default action of an event, so stop listening to it passively
+  preventDefault(element, event_name) {
+    if (!is_scroll_blocking(event_name)) {
+      return;
+    }
+    const global = this.global[event_name];
+    if (global !== undefined && global.passive) {
+      this.root.removeEventListener(event_name, global.callback);
+      this.root.addEventListener(event_name, global.callback, { passive: false });
+      global.passive = false;
+    }
+    const id = element.getAttribute("data-dioxus-id");
+    const local = this.local[id] && this.local[id][event_name];
+    if (local !== undefined) {
+      element.removeEventListener(event_name, local.callback);
+      element.addEventListener(event_name, local.callback, { passive: false });
+    }
+  }
+
+  // events dispatched on the document or window never reach the root, so each listener is attached there directly
+  createGlobal(target, event_name, id, handler) {
+    const key = `${event_name}-${id}`;
+    this.outside[key] = { target, handler };
+    target.addEventListener(event_name, handler);
+  }
+
+  removeGlobal(event_name, id) {
+    const key = `${event_name}-${id}`;
+    const listener = this.outside[key];
+    if (listener !== undefined) {
+      listener.target.removeEventListener(event_name, listener.handler);
+      delete this.outside[key];
+    }
+  }
+}
+
+// iOS Safari only dispatches clicks on elements that look interactive (links, buttons, elements with
+// `cursor: pointer`) or that have a click listener of their own. A plain div with an onclick would never see its clicks
+// reach the delegated listener at the root, so we give those elements an empty listener.
+// Don't remove this just because clicks work on other browsers!
+const needs_click_patch =
+  /iP(hone|ad|od)/.test(navigator.userAgent) ||
+  (navigator.platform === "MacIntel" && navigator.maxTouchPoints > 1);
+
+function noop() {}
+
+// the browser has to wait for non-passive listeners of these events before it can scroll, so we listen to them
+// passively unless an element asks to prevent their default action with `prevent_default: "onwheel"`
+function is_scroll_blocking(event_name) {
+  return event_name === "touchstart" || event_name === "touchmove" || event_name === "wheel";
+}
+
+function prevents_default(element, event_name) {
+  const prevent = element.getAttribute("dioxus-prevent-default");
+  return prevent !== null && prevent.replace(/^(on)*/, "") === event_name;
+}
+
+// Watches elements with an observer like IntersectionObserver, and sends what it reports as events for the elements
+class ElementObserver {
+  // initial returns the data of an event to send as soon as a node starts being watched, for when the observer itself
+  // wouldn't report it
+  constructor(Observer, name, serialize, initial = null) {
+    this.name = name;
+    this.initial = initial;
+    this.observed = new Map();
+    // nodes that started being watched by the edits being applied
+    this.pending = [];
+    this.observer = new Observer((entries) => {
+      for (const entry of entries) {
+        const id = this.observed.get(entry.target);
+        if (id !== undefined) {
+          this.send(id, serialize(entry));
+        }
+      }
+    });
+  }
+  send(id, data) {
+    window.ipc.postMessage(
+      serializeIpcMessage("user_event", {
+        name: this.name,
+        element: id,
+        data,
+        bubbles: false,
+      })
+    );
+  }
+  observe(node, id) {
+    this.observed.set(node, id);
+    this.observer.observe(node);
+    if (this.initial !== null) {
+      this.pending.push(node);
+    }
+  }
+  unobserve(id) {
+    for (const [node, observedId] of this.observed) {
+      if (observedId === id) {
+        this.observer.unobserve(node);
+        this.observed.delete(node);
+      }
+    }
+  }
+  // core doesn't remove the listeners of elements that are removed along with their parent, so stop watching
+  // everything that isn't on the page anymore
+  sweep() {
+    for (const node of this.observed.keys()) {
+      if (!node.isConnected) {
+        this.observer.unobserve(node);
+        this.observed.delete(node);
+      }
+    }
+    for (const node of this.pending) {
+      const id = this.observed.get(node);
+      const data = id === undefined ? null : this.initial(node);
+      if (data !== null) {
+        this.send(id, data);
+      }
+    }
+    this.pending = [];
+  }
 }
 
 class Interpreter {
@@ -61,6 +208,11 @@ class Interpreter {
     this.handlers = {};
     this.templates = {};
     this.lastNodeWasText = false;
+    // elements with an onmounted listener that were created by the edits being applied
+    this.mounted = [];
+    // created the first time an element listens to onvisible or onresize
+    this.visibility = null;
+    this.sizes = null;
   }
   top() {
     return this.stack[this.stack.length - 1];
@@ -129,13 +281,22 @@ class Interpreter {
   }
   NewEventListener(event_name, root, bubbles, handler) {
     const element = this.nodes[root];
+    element.listening = (element.listening || 0) + 1;
     element.setAttribute("data-dioxus-id", `${root}`);
     this.listeners.create(event_name, element, handler, bubbles);
   }
   RemoveEventListener(root, event_name, bubbles) {
+    if (global_event_target(event_name) !== null) {
+      this.listeners.removeGlobal(event_name, root);
+      return;
+    }
     const element = this.nodes[root];
-    element.removeAttribute(`data-dioxus-id`);
+    // the listeners are looked up through the id, so keep it around until the element's last listener is removed
     this.listeners.remove(element, event_name, bubbles);
+    element.listening -= 1;
+    if (element.listening === 0) {
+      element.removeAttribute(`data-dioxus-id`);
+    }
   }
   SetText(root, text) {
     this.nodes[root].textContent = text;
@@ -162,9 +323,7 @@ class Interpreter {
     } else {
       switch (name) {
         case "value":
-          if (value !== node.value) {
-            node.value = value;
-          }
+          set_value(node, value);
           break;
         case "checked":
           node.checked = value === "true";
@@ -182,6 +341,9 @@ class Interpreter {
           } else {
             node.setAttribute(name, value);
           }
+          if (name === "dioxus-prevent-default" && node.listening) {
+            this.listeners.preventDefault(node, value.replace(/^(on)*/, ""));
+          }
       }
     }
   }
@@ -212,17 +374,71 @@ class Interpreter {
     for (let edit of edits.edits) {
       this.handleEdit(edit);
     }
+
+    // the mounted event isn't a browser event, we send it once the elements are on the page
+    for (let id of this.mounted) {
+      window.ipc.postMessage(
+        serializeIpcMessage("user_event", {
+          name: "mounted",
+          element: id,
+          data: { id },
+          bubbles: false,
+        })
+      );
+    }
+    this.mounted = [];
+
+    if (this.visibility !== null) {
+      this.visibility.sweep();
+    }
+    if (this.sizes !== null) {
+      this.sizes.sweep();
+    }
+  }
+
+  ObserveVisibility(id) {
+    if (this.visibility === null) {
+      this.visibility = new ElementObserver(IntersectionObserver, "visible", (entry) => ({
+        is_intersecting: entry.isIntersecting,
+        intersection_ratio: entry.intersectionRatio,
+      }));
+    }
+    this.visibility.observe(this.nodes[id], id);
+  }
+
+  ObserveSize(id) {
+    if (this.sizes === null) {
+      this.sizes = new ElementObserver(
+        ResizeObserver,
+        "resize",
+        (entry) => ({
+          x: entry.contentRect.x,
+          y: entry.contentRect.y,
+          width: entry.contentRect.width,
+          height: entry.contentRect.height,
+        }),
+        // browsers only report the first size of an element if it isn't empty
+        (node) => {
+          const rect = node.getBoundingClientRect();
+          if (rect.width === 0 && rect.height === 0) {
+            return { x: 0, y: 0, width: 0, height: 0 };
+          }
+          return null;
+        }
+      );
+    }
+    this.sizes.observe(this.nodes[id], id);
   }
 
   SaveTemplate(template) {
     let roots = [];
     for (let root of template.roots) {
-      roots.push(this.MakeTemplateNode(root));
+      roots.push(this.MakeTemplateNode(root, null));
     }
     this.templates[template.name] = roots;
   }
 
-  MakeTemplateNode(node) {
+  MakeTemplateNode(node, parentNamespace) {
     switch (node.type) {
       case "Text":
         return document.createTextNode(node.text);
@@ -234,9 +450,14 @@ class Interpreter {
         return document.createTextNode("placeholder");
       case "Element":
         let el;
+        let namespace = element_namespace(
+          node.tag,
+          node.namespace,
+          parentNamespace
+        );
 
-        if (node.namespace != null) {
-          el = document.createElementNS(node.namespace, node.tag);
+        if (namespace != null) {
+          el = document.createElementNS(namespace, node.tag);
         } else {
           el = document.createElement(node.tag);
         }
@@ -247,8 +468,10 @@ class Interpreter {
           }
         }
 
+        // Children of a foreignObject are back in the html namespace
+        let childNamespace = node.tag == "foreignObject" ? null : namespace;
         for (let child of node.children) {
-          el.appendChild(this.MakeTemplateNode(child));
+          el.appendChild(this.MakeTemplateNode(child, childNamespace));
         }
 
         return el;
@@ -338,12 +561,59 @@ class Interpreter {
       case "RemoveAttribute":
         this.RemoveAttribute(edit.id, edit.name, edit.ns);
         break;
-      case "RemoveEventListener":
-        this.RemoveEventListener(edit.id, edit.name);
+      case "RemoveEventListener": {
+        const event_name = capture_event(edit.name) ?? edit.name;
+        if (event_name === "mounted") {
+          break;
+        }
+        if (event_name === "visible") {
+          if (this.visibility !== null) {
+            this.visibility.unobserve(edit.id);
+          }
+          break;
+        }
+        if (event_name === "resize") {
+          if (this.sizes !== null) {
+            this.sizes.unobserve(edit.id);
+          }
+          break;
+        }
+        this.RemoveEventListener(edit.id, event_name, event_bubbles(event_name));
         break;
+      }
       case "NewEventListener":
+        // core calls capture listeners itself, so they only need the event delivered like any other
+        let event_name = capture_event(edit.name) ?? edit.name;
+        if (event_name === "mounted") {
+          this.mounted.push(edit.id);
+          break;
+        }
+        if (event_name === "visible") {
+          this.ObserveVisibility(edit.id);
+          break;
+        }
+        if (event_name === "resize") {
+          this.ObserveSize(edit.id);
+          break;
+        }
+        let globalTarget = global_event_target(event_name);
+        if (globalTarget !== null) {
+          let id = edit.id;
+          let name = event_name;
+          this.listeners.createGlobal(globalTarget, name, id, (event) => {
+            window.ipc.postMessage(
+              serializeIpcMessage("user_event", {
+                name,
+                element: id,
+                data: serialize_event(event),
+                bubbles: false,
+              })
+            );
+          });
+          break;
+        }
 
-        let bubbles = event_bubbles(edit.name);
+        let bubbles = event_bubbles(event_name);
 
         // this handler is only provided on desktop implementations since this
         // method is not used by the web implementation
@@ -407,9 +677,12 @@ class Interpreter {
                 let element = target.elements[x];
                 let name = element.getAttribute("name");
                 if (name != null) {
+                  if (element instanceof HTMLSelectElement && element.multiple) {
+                    contents.selected_values[name] = get_selected_options(element);
+                  }
                   if (element.getAttribute("type") === "checkbox") {
                     // @ts-ignore
-                    contents.values[name] = element.checked ? "true" : "false";
+                    contents.values[name] = get_checkbox_value(element);
                   } else if (element.getAttribute("type") === "radio") {
                     if (element.checked) {
                       contents.values[name] = element.value;
@@ -428,7 +701,7 @@ class Interpreter {
             }
             window.ipc.postMessage(
               serializeIpcMessage("user_event", {
-                name: edit.name,
+                name: event_name,
                 element: parseInt(realId),
                 data: contents,
                 bubbles,
@@ -436,7 +709,7 @@ class Interpreter {
             );
           }
         };
-        this.NewEventListener(edit.name, edit.id, bubbles, handler);
+        this.NewEventListener(event_name, edit.id, bubbles, handler);
         break;
     }
   }
@@ -477,12 +750,79 @@ function get_mouse_data(event) {
   };
 }
 
+// Browsers merge fast pointer moves into one event, but keep every position they skipped over
+function get_coalesced_pointer_samples(event) {
+  const coalesced =
+    typeof event.getCoalescedEvents === "function"
+      ? event.getCoalescedEvents()
+      : [];
+  return (coalesced.length > 0 ? coalesced : [event]).map((sample) => ({
+    client_x: sample.clientX,
+    client_y: sample.clientY,
+    page_x: sample.pageX,
+    page_y: sample.pageY,
+    screen_x: sample.screenX,
+    screen_y: sample.screenY,
+    pressure: sample.pressure,
+    time_stamp: sample.timeStamp,
+  }));
+}
+
+function get_touch_points(list) {
+  return Array.from(list, (touch) => ({
+    identifier: touch.identifier,
+    client_x: touch.clientX,
+    client_y: touch.clientY,
+    page_x: touch.pageX,
+    page_y: touch.pageY,
+    screen_x: touch.screenX,
+    screen_y: touch.screenY,
+    radius_x: touch.radiusX,
+    radius_y: touch.radiusY,
+    rotation_angle: touch.rotationAngle,
+    force: touch.force,
+  }));
+}
+
+// Checkboxes can be in a third, indeterminate state, which is common for "select all" checkboxes
+function get_checkbox_value(target) {
+  if (target.indeterminate) {
+    return "indeterminate";
+  }
+  return target.checked ? "true" : "false";
+}
+
+// The markup of a contenteditable element, or null for form controls
+function get_contenteditable_html(target) {
+  if (
+    target instanceof HTMLInputElement ||
+    target instanceof HTMLTextAreaElement ||
+    target instanceof HTMLSelectElement ||
+    target instanceof HTMLFormElement
+  ) {
+    return null;
+  }
+  return target.innerHTML ?? null;
+}
+
+// Every selected value of a <select multiple>, or an empty list for anything else
+function get_selected_options(target) {
+  if (!(target instanceof HTMLSelectElement) || !target.multiple) {
+    return [];
+  }
+  return Array.from(target.selectedOptions).map((option) => option.value);
+}
+
 function serialize_event(event) {
   switch (event.type) {
     case "copy":
     case "cut":
-    case "past": {
-      return {};
+    case "paste": {
+      // Files can't be sent across to the virtualdom, so only the text is serialized
+      const text = event.clipboardData
+        ? event.clipboardData.getData("text/plain")
+        : "";
+      return { text: text || null };
     }
     case "compositionend":
     case "compositionstart":
@@ -507,6 +847,7 @@ function serialize_event(event) {
         repeat,
         which,
         code,
+        isComposing,
       } = event;
       return {
         char_code: charCode,
@@ -520,16 +861,27 @@ function serialize_event(event) {
         repeat: repeat,
         which: which,
         code,
+        is_composing: isComposing,
       };
     }
     case "focus":
-    case "blur": {
-      return {};
+    case "blur":
+    case "focusin":
+    case "focusout": {
+      // relatedTarget is null when focus moves in from (or out to) outside the document
+      const related = event.relatedTarget;
+      const id =
+        related instanceof Element
+          ? parseInt(related.getAttribute("data-dioxus-id"))
+          : NaN;
+      return { related_target: isNaN(id) ? null : id };
     }
     case "change": {
       let target = event.target;
       let value;
-      if (target.type === "checkbox" || target.type === "radio") {
+      if (target.type === "checkbox") {
+        value = get_checkbox_value(target);
+      } else if (target.type === "radio") {
         value = target.checked ? "true" : "false";
       } else {
         value = target.value ?? target.textContent;
@@ -537,6 +889,19 @@ function serialize_event(event) {
       return {
         value: value,
         values: {},
+        selected: get_selected_options(target),
+        // NaN can't be sent as JSON, and means the input is empty or not numeric
+        value_as_number: Number.isNaN(target.valueAsNumber)
+          ? null
+          : target.valueAsNumber ?? null,
+        html: get_contenteditable_html(target),
+        selected_values: {},
+      };
+    }
+    case "beforeinput": {
+      return {
+        input_type: event.inputType ?? "",
+        data: event.data ?? null,
       };
     }
     case "input":
@@ -547,12 +912,19 @@ function serialize_event(event) {
       let value = target.value ?? target.textContent;
 
       if (target.type === "checkbox") {
-        value = target.checked ? "true" : "false";
+        value = get_checkbox_value(target);
       }
 
       return {
         value: value,
         values: {},
+        selected: get_selected_options(target),
+        // NaN can't be sent as JSON, and means the input is empty or not numeric
+        value_as_number: Number.isNaN(target.valueAsNumber)
+          ? null
+          : target.valueAsNumber ?? null,
+        html: get_contenteditable_html(target),
+        selected_values: {},
       };
     }
     case "drag":
@@ -563,7 +935,20 @@ function serialize_event(event) {
     case "dragover":
     case "dragstart":
     case "drop": {
-      return { mouse: get_mouse_data(event) };
+      const types = [];
+      const data = {};
+      const transfer = event.dataTransfer;
+      if (transfer) {
+        for (const type of transfer.types) {
+          types.push(type);
+          // Files aren't string data, and reading the data of a protected transfer gives an empty string
+          const value = type === "Files" ? "" : transfer.getData(type);
+          if (value !== "") {
+            data[type] = value;
+          }
+        }
+      }
+      return { mouse: get_mouse_data(event), types, data };
     }
     case "click":
     case "contextmenu":
@@ -587,7 +972,8 @@ function serialize_event(event) {
     case "pointerenter":
     case "pointerleave":
     case "pointerover":
-    case "pointerout": {
+    case "pointerout":
+    case "pointerrawupdate": {
       const {
         altKey,
         button,
@@ -635,10 +1021,31 @@ function serialize_event(event) {
         twist: twist,
         pointer_type: pointerType,
         is_primary: isPrimary,
+        coalesced: get_coalesced_pointer_samples(event),
       };
     }
     case "select": {
-      return {};
+      const target = event.target;
+      if (
+        target instanceof HTMLInputElement ||
+        target instanceof HTMLTextAreaElement
+      ) {
+        const { selectionStart, selectionEnd, value } = target;
+        return {
+          start: selectionStart,
+          end: selectionEnd,
+          text:
+            selectionStart === null || selectionEnd === null
+              ? null
+              : value.substring(selectionStart, selectionEnd),
+        };
+      }
+      const selection = window.getSelection();
+      return {
+        start: null,
+        end: null,
+        text: selection ? selection.toString() : null,
+      };
     }
     case "touchcancel":
     case "touchend":
@@ -646,13 +1053,13 @@ function serialize_event(event) {
     case "touchstart": {
       const { altKey, ctrlKey, metaKey, shiftKey } = event;
       return {
-        // changed_touches: event.changedTouches,
-        // target_touches: event.targetTouches,
-        // touches: event.touches,
         alt_key: altKey,
         ctrl_key: ctrlKey,
         meta_key: metaKey,
         shift_key: shiftKey,
+        touches: get_touch_points(event.touches),
+        changed_touches: get_touch_points(event.changedTouches),
+        target_touches: get_touch_points(event.targetTouches),
       };
     }
     case "scroll": {
@@ -708,12 +1115,79 @@ function serialize_event(event) {
     case "timeupdate":
     case "volumechange":
     case "waiting": {
-      return {};
+      const target = event.target;
+      if (!(target instanceof HTMLMediaElement)) {
+        return {
+          current_time: 0,
+          duration: 0,
+          volume: 1,
+          muted: false,
+          paused: true,
+          playback_rate: 1,
+        };
+      }
+      const { currentTime, duration, volume, muted, paused, playbackRate } =
+        target;
+      return {
+        current_time: currentTime,
+        // NaN and Infinity can't be sent as JSON
+        duration: Number.isFinite(duration) ? duration : 0,
+        volume: volume,
+        muted: muted,
+        paused: paused,
+        playback_rate: playbackRate,
+      };
     }
-    case "toggle": {
-      return {};
+    case "toggle":
+    case "beforetoggle": {
+      const { oldState, newState, target } = event;
+      return {
+        old_state: oldState ?? "",
+        new_state: newState ?? "",
+        open:
+          target instanceof HTMLDetailsElement
+            ? target.open
+            : newState === "open",
+      };
+    }
+    case "securitypolicyviolation": {
+      const {
+        documentURI,
+        blockedURI,
+        violatedDirective,
+        effectiveDirective,
+        originalPolicy,
+        sourceFile,
+        lineNumber,
+        columnNumber,
+      } = event;
+      return {
+        document_uri: documentURI,
+        blocked_uri: blockedURI,
+        violated_directive: violatedDirective,
+        effective_directive: effectiveDirective,
+        original_policy: originalPolicy,
+        source_file: sourceFile,
+        line_number: lineNumber,
+        column_number: columnNumber,
+      };
+    }
+    case "popstate":
+    case "hashchange": {
+      const state = window.history.state;
+      return {
+        pathname: window.location.pathname,
+        hash: window.location.hash,
+        state: state == null ? null : JSON.stringify(state),
+        old_url: event.type === "hashchange" ? event.oldURL : null,
+        new_url: event.type === "hashchange" ? event.newURL : null,
+      };
     }
     default: {
+      if (event instanceof CustomEvent) {
+        const detail = event.detail;
+        return { detail: detail == null ? null : JSON.stringify(detail) };
+      }
       return {};
     }
   }
@@ -782,6 +1256,8 @@ function event_bubbles(event) {
       return true;
     case "blur":
       return false;
+    case "beforeinput":
+      return true;
     case "change":
       return true;
     case "input":
@@ -852,6 +1328,8 @@ function event_bubbles(event) {
       return true;
     case "pointerout":
       return true;
+    case "pointerrawupdate":
+      return true;
     case "select":
       return true;
     case "touchcancel":
@@ -919,8 +1397,78 @@ function event_bubbles(event) {
     case "transitionend":
       return true;
     case "toggle":
+      return false;
+    case "beforetoggle":
+      return false;
+    case "securitypolicyviolation":
       return true;
+    case "popstate":
+      return false;
+    case "hashchange":
+      return false;
+    case "mounted":
+      return false;
+    case "visible":
+      return false;
+    case "resize":
+      return false;
   }
 
   return true;
 }
+
+function capture_event(name) {
+  // these events end in capture without being capture listeners
+  if (name === "gotpointercapture" || name === "lostpointercapture") {
+    return null;
+  }
+  if (name.endsWith("capture") && name.length > "capture".length) {
+    return name.slice(0, -"capture".length);
+  }
+  return null;
+}
+
+function global_event_target(event) {
+  switch (event) {
+    case "securitypolicyviolation":
+      return document;
+    case "popstate":
+    case "hashchange":
+      return window;
+  }
+
+  return null;
+}
+
+// Elements without a namespace of their own inherit the namespace of their parent
+function element_namespace(tag, namespace, parentNamespace) {
+  if (namespace != null) {
+    return namespace;
+  }
+
+  switch (tag) {
+    case "svg":
+      return "http://www.w3.org/2000/svg";
+    case "math":
+      return "http://www.w3.org/1998/Math/MathML";
+  }
+
+  return parentNamespace;
+}
+
+// Assigning the value of a focused input moves the caret to the end, which would make editing the middle of a
+// controlled input impossible, so we put the selection back where it was
+function set_value(node, value) {
+  if (value === node.value) {
+    return;
+  }
+
+  if (node === document.activeElement && typeof node.selectionStart === "number") {
+    const start = node.selectionStart;
+    const end = node.selectionEnd;
+    node.value = value;
+    node.setSelectionRange(start, end);
+  } else {
+    node.value = value;
+  }
+}
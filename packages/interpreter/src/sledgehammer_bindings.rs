@@ -12,43 +12,91 @@ mod js {
             // non bubbling events listen at the element the listener was created at
             this.local = {};
             this.root = null;
+            // elements outside of the root that bubbling events are delegated from too, like the containers of portals
+            this.portals = [];
             this.handler = null;
         }
 
+        roots() {
+            return [this.root, ...this.portals];
+        }
+
+        addRoot(root) {
+            this.portals.push(root);
+            for (const event_name in this.global) {
+                root.addEventListener(event_name, this.handler, { passive: this.global[event_name].passive });
+            }
+        }
+
+        removeRoot(root) {
+            this.portals = this.portals.filter((portal) => portal !== root);
+            for (const event_name in this.global) {
+                root.removeEventListener(event_name, this.handler);
+            }
+        }
+
         create(event_name, element, bubbles) {
+            if (event_name === "click" && needs_click_patch) {
+                element.addEventListener("click", noop);
+            }
+            const passive = is_scroll_blocking(event_name) && (always_passive || !prevents_default(element, event_name));
             if (bubbles) {
                 if (this.global[event_name] === undefined) {
                     this.global[event_name] = {};
                     this.global[event_name].active = 1;
-                    this.root.addEventListener(event_name, this.handler);
+                    this.global[event_name].passive = passive;
+                    for (const root of this.roots()) {
+                        root.addEventListener(event_name, this.handler, { passive });
+                    }
                 } else {
                     this.global[event_name].active++;
+                    if (!passive) {
+                        this.preventDefault(element, event_name);
+                    }
                 }
             }
             else {
+                // an element can have more than one listener for the same event, so only attach the dom listener once
                 const id = element.getAttribute("data-dioxus-id");
                 if (!this.local[id]) {
                     this.local[id] = {};
                 }
-                element.addEventListener(event_name, this.handler);
+                if (this.local[id][event_name] === undefined) {
+                    this.local[id][event_name] = 1;
+                    element.addEventListener(event_name, this.handler, { passive });
+                } else {
+                    this.local[id][event_name]++;
+                }
             }
         }
 
         remove(element, event_name, bubbles) {
             if (bubbles) {
+                if (this.global[event_name] === undefined) {
+                    return;
+                }
                 this.global[event_name].active--;
                 if (this.global[event_name].active === 0) {
-                    this.root.removeEventListener(event_name, this.global[event_name].callback);
+                    for (const root of this.roots()) {
+                        root.removeEventListener(event_name, this.handler);
+                    }
                     delete this.global[event_name];
                 }
             }
             else {
+                // only detach the dom listener once the last listener for this event on the element is gone
                 const id = element.getAttribute("data-dioxus-id");
-                delete this.local[id][event_name];
-                if (this.local[id].length === 0) {
-                    delete this.local[id];
+                if (this.local[id] === undefined || this.local[id][event_name] === undefined) {
+                    return;
+                }
+                this.local[id][event_name]--;
+                if (this.local[id][event_name] === 0) {
+                    element.removeEventListener(event_name, this.handler);
+                    delete this.local[id][event_name];
+                    if (Object.keys(this.local[id]).length === 0) {
+                        delete this.local[id];
+                    }
                 }
-                element.removeEventListener(event_name, this.handler);
             }
         }
 
@@ -56,6 +104,59 @@ mod js {
             const id = element.getAttribute("data-dioxus-id");
             delete this.local[id];
         }
+
+        // an element wants to prevent the default action of an event, so stop listening to it passively
+        preventDefault(element, event_name) {
+            if (always_passive || !is_scroll_blocking(event_name)) {
+                return;
+            }
+            const global = this.global[event_name];
+            if (global !== undefined && global.passive) {
+                for (const root of this.roots()) {
+                    root.removeEventListener(event_name, this.handler);
+                    root.addEventListener(event_name, this.handler, { passive: false });
+                }
+                global.passive = false;
+            }
+            const id = element.getAttribute("data-dioxus-id");
+            if (this.local[id] !== undefined && this.local[id][event_name] !== undefined) {
+                element.removeEventListener(event_name, this.handler);
+                element.addEventListener(event_name, this.handler, { passive: false });
+            }
+        }
+    }
+    // iOS Safari only dispatches clicks on elements that look interactive (links, buttons, elements with
+    // `cursor: pointer`) or that have a click listener of their own. A plain div with an onclick would never see its
+    // clicks reach the delegated listener at the root, so we give those elements an empty listener.
+    // Don't remove this just because clicks work on other browsers!
+    const needs_click_patch = /iP(hone|ad|od)/.test(navigator.userAgent) || (navigator.platform === "MacIntel" && navigator.maxTouchPoints > 1);
+    function noop() {}
+    // set with `Config::default_passive_events` to keep scroll blocking events passive even if an element wants to
+    // prevent their default action
+    let always_passive = false;
+    // the browser has to wait for non-passive listeners of these events before it can scroll, so we listen to them
+    // passively unless an element asks to prevent their default action with `prevent_default: "onwheel"`
+    function is_scroll_blocking(event_name) {
+        return event_name === "touchstart" || event_name === "touchmove" || event_name === "wheel";
+    }
+    function prevents_default(element, event_name) {
+        const prevent = element.getAttribute("dioxus-prevent-default");
+        return prevent !== null && prevent.replace(/^(on)*/, "") === event_name;
+    }
+    // assigning the value of a focused input moves the caret to the end, which would make editing the middle of a
+    // controlled input impossible, so we put the selection back where it was
+    function SetValue(node, value) {
+        if (value === node.value) {
+            return;
+        }
+        if (node === document.activeElement && typeof node.selectionStart === "number") {
+            const start = node.selectionStart;
+            const end = node.selectionEnd;
+            node.value = value;
+            node.setSelectionRange(start, end);
+        } else {
+            node.value = value;
+        }
     }
     function SetAttributeInner(node, field, value, ns) {
         const name = field;
@@ -70,9 +171,7 @@ mod js {
         } else {
             switch (name) {
                 case "value":
-                    if (value !== node.value) {
-                        node.value = value;
-                    }
+                    SetValue(node, value);
                     break;
                 case "checked":
                     node.checked = value === "true";
@@ -90,6 +189,9 @@ mod js {
                     } else {
                         node.setAttribute(name, value);
                     }
+                    if (name === "dioxus-prevent-default" && node.listening) {
+                        listeners.preventDefault(node, value.replace(/^(on)*/, ""));
+                    }
             }
         }
     }
@@ -116,6 +218,18 @@ mod js {
     export function set_node(id, node) {
         nodes[id] = node;
     }
+    export function get_node(id) {
+        return nodes[id];
+    }
+    export function set_always_passive(passive) {
+        always_passive = passive;
+    }
+    export function add_listener_root(root) {
+        listeners.addRoot(root);
+    }
+    export function remove_listener_root(root) {
+        listeners.removeRoot(root);
+    }
     export function initilize(root, handler) {
         listeners.handler = handler;
         nodes = [root];
@@ -166,8 +280,20 @@ mod js {
         #[wasm_bindgen]
         pub fn set_node(id: u32, node: Node);
 
+        #[wasm_bindgen]
+        pub fn get_node(id: u32) -> Option<Node>;
+
         #[wasm_bindgen]
         pub fn initilize(root: Node, handler: &Function);
+
+        #[wasm_bindgen]
+        pub fn set_always_passive(passive: bool);
+
+        #[wasm_bindgen]
+        pub fn add_listener_root(root: Node);
+
+        #[wasm_bindgen]
+        pub fn remove_listener_root(root: Node);
     }
 
     fn mount_to_root() {
@@ -207,7 +333,7 @@ mod js {
         r#"node = nodes[id]; if(node.listening){node.listening += 1;}else{node.listening = 1;} node.setAttribute('data-dioxus-id', `\${id}`); listeners.create($event_name$, node, $bubbles$);"#
     }
     fn remove_event_listener(event_name: &str<u8, evt>, id: u32, bubbles: u8) {
-        "{node = nodes[$id$]; node.listening -= 1; node.removeAttribute('data-dioxus-id'); listeners.remove(node, $event_name$, $bubbles$);}"
+        "{node = nodes[$id$]; listeners.remove(node, $event_name$, $bubbles$); node.listening -= 1; if (node.listening === 0) { node.removeAttribute('data-dioxus-id'); }}"
     }
     fn set_text(id: u32, text: &str) {
         "{nodes[$id$].textContent = $text$;}"
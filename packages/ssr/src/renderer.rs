@@ -70,12 +70,30 @@ impl Renderer {
             .or_insert_with(|| Rc::new(StringCache::from_template(template).unwrap()))
             .clone();
 
+        // The dynamic attributes of an element are next to each other, so we only need to remember the last element
+        let mut last_element_id = None;
+
         for segment in entry.segments.iter() {
             match segment {
                 Segment::Attr(idx) => {
                     let attr = &template.dynamic_attrs[*idx];
+
+                    // Elements with dynamic attributes or listeners need to be found again when the page is hydrated
+                    if self.pre_render {
+                        let id = attr.mounted_element.get();
+                        if last_element_id != Some(id) {
+                            write!(buf, " data-dioxus-id=\"{}\"", id.0)?;
+                            last_element_id = Some(id);
+                        }
+                    }
+
                     match attr.value {
-                        AttributeValue::Text(value) => write!(buf, " {}=\"{}\"", attr.name, value)?,
+                        AttributeValue::Text(value) => write!(
+                            buf,
+                            " {}=\"{}\"",
+                            attr.name,
+                            askama_escape::escape(value, askama_escape::Html)
+                        )?,
                         AttributeValue::Bool(value) => write!(buf, " {}={}", attr.name, value)?,
                         _ => {}
                     };
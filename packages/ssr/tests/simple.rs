@@ -74,3 +74,31 @@ fn fragments() {
         "<div></div>"
     );
 }
+
+#[test]
+fn escapes_attributes() {
+    let value = "<b>&\"";
+    assert_eq!(
+        dioxus_ssr::render_lazy(rsx! {
+            div { class: "\"quoted\"", title: "{value}" }
+        }),
+        "<div class=\"&quot;quoted&quot;\" title=\"&lt;b&gt;&amp;&quot;\"></div>"
+    );
+}
+
+#[test]
+fn pre_render_writes_element_ids() {
+    fn app(cx: Scope) -> Element {
+        render! {
+            div { onclick: |_| {}, "hello!" }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    assert_eq!(
+        dioxus_ssr::pre_render(&dom),
+        "<div data-dioxus-id=\"1\">hello!</div>"
+    );
+}
@@ -199,33 +199,36 @@ impl InnerInputState {
 
         if old_focus != self.focus_state.last_focused_id {
             // elements with listeners will always have a element id
-            if let Some(id) = self.focus_state.last_focused_id {
-                let element = dom.tree.get(id).unwrap();
-                if let Some(id) = element.node_data.element_id {
-                    resolved_events.push(Event {
-                        name: "focus",
-                        id,
-                        data: Rc::new(FocusData {}),
-                        bubbles: event_bubbles("focus"),
-                    });
-                    resolved_events.push(Event {
-                        name: "focusin",
-                        id,
-                        data: Rc::new(FocusData {}),
-                        bubbles: event_bubbles("focusin"),
-                    });
-                }
+            let element_id = |id| dom.tree.get(id).unwrap().node_data.element_id;
+            let new_focus = self.focus_state.last_focused_id.and_then(element_id);
+            let old_focus = old_focus.and_then(element_id);
+
+            if let Some(id) = new_focus {
+                let data = Rc::new(FocusData {
+                    related_target: old_focus,
+                });
+                resolved_events.push(Event {
+                    name: "focus",
+                    id,
+                    data: data.clone(),
+                    bubbles: event_bubbles("focus"),
+                });
+                resolved_events.push(Event {
+                    name: "focusin",
+                    id,
+                    data,
+                    bubbles: event_bubbles("focusin"),
+                });
             }
             if let Some(id) = old_focus {
-                let element = dom.tree.get(id).unwrap();
-                if let Some(id) = element.node_data.element_id {
-                    resolved_events.push(Event {
-                        name: "focusout",
-                        id,
-                        data: Rc::new(FocusData {}),
-                        bubbles: event_bubbles("focusout"),
-                    });
-                }
+                resolved_events.push(Event {
+                    name: "focusout",
+                    id,
+                    data: Rc::new(FocusData {
+                        related_target: new_focus,
+                    }),
+                    bubbles: event_bubbles("focusout"),
+                });
             }
         }
 
@@ -33,6 +33,7 @@ pub(crate) fn Button<'a>(cx: Scope<'a, ButtonProps>) -> Element<'a> {
                 value: text.to_string(),
                 values: HashMap::new(),
                 files: None,
+                ..Default::default()
             });
         }
         state.set(new_state);
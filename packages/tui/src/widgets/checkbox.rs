@@ -57,6 +57,7 @@ pub(crate) fn CheckBox<'a>(cx: Scope<'a, CheckBoxProps>) -> Element<'a> {
                 },
                 values: HashMap::new(),
                 files: None,
+                ..Default::default()
             });
         }
         state.set(new_state);
@@ -85,6 +85,7 @@ pub(crate) fn NumbericInput<'a>(cx: Scope<'a, NumbericInputProps>) -> Element<'a
                 value: text,
                 values: HashMap::new(),
                 files: None,
+                ..Default::default()
             });
         }
     };
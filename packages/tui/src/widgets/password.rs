@@ -94,6 +94,7 @@ pub(crate) fn Password<'a>(cx: Scope<'a, PasswordProps>) -> Element<'a> {
                 value: text.clone(),
                 values: HashMap::new(),
                 files: None,
+                ..Default::default()
             });
         }
 
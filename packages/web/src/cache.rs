@@ -221,6 +221,8 @@ pub static BUILTIN_INTERNED_STRINGS: &[&str] = &[
     "onmouseover",
     "onmouseup",
     "onmousewheel",
+    "onmounted",
+    "onvisible",
     "onoffline",
     "ononline",
     "onpageshow",
@@ -242,6 +244,9 @@ pub static BUILTIN_INTERNED_STRINGS: &[&str] = &[
     "onsuspend",
     "ontimeupdate",
     "ontoggle",
+    "onbeforetoggle",
+    "onpopstate",
+    "onhashchange",
     "onunload",
     "onvolumechange",
     "onwaiting",
@@ -11,8 +11,11 @@ pub struct Config {
     #[cfg(feature = "hydrate")]
     pub(crate) hydrate: bool,
     pub(crate) rootname: String,
+    pub(crate) root: Option<web_sys::Element>,
     pub(crate) cached_strings: Vec<String>,
     pub(crate) default_panic_hook: bool,
+    pub(crate) default_passive_events: bool,
+    pub(crate) time_slice: Option<f64>,
 }
 
 impl Default for Config {
@@ -21,8 +24,11 @@ impl Default for Config {
             #[cfg(feature = "hydrate")]
             hydrate: false,
             rootname: "main".to_string(),
+            root: None,
             cached_strings: Vec::new(),
             default_panic_hook: true,
+            default_passive_events: false,
+            time_slice: None,
         }
     }
 }
@@ -42,6 +48,8 @@ impl Config {
     /// work and suspended nodes.
     ///
     /// Dioxus will load up all the elements with the `dio_el` data attribute into memory when the page is loaded.
+    ///
+    /// If the root element is empty when the app starts, there's nothing to hydrate and the app is rendered from scratch.
     pub fn hydrate(mut self, f: bool) -> Self {
         self.hydrate = f;
         self
@@ -55,6 +63,15 @@ impl Config {
         self
     }
 
+    /// Set the element that Dioxus will use as the root, instead of looking one up with [`Config::rootname`].
+    ///
+    /// This lets you mount into an element you already have a handle to, like the shadow root of a web component or
+    /// one of several apps on the same page.
+    pub fn with_root_element(mut self, root: web_sys::Element) -> Self {
+        self.root = Some(root);
+        self
+    }
+
     /// Set the name of the element that Dioxus will use as the root.
     ///
     /// This is akint to calling React.render() on the element with the specified name.
@@ -70,4 +87,29 @@ impl Config {
         self.default_panic_hook = f;
         self
     }
+
+    /// Set whether scroll blocking events (`touchstart`, `touchmove` and `wheel`) are always listened to passively.
+    ///
+    /// The browser has to wait for active listeners of these events before it can scroll. Dioxus listens to them
+    /// passively unless an element asks to prevent their default action with `prevent_default`. Turning this on keeps
+    /// them passive even then, so scrolling stays smooth but `prevent_default` does nothing for these events.
+    ///
+    /// This is off by default.
+    pub fn default_passive_events(mut self, passive: bool) -> Self {
+        self.default_passive_events = passive;
+        self
+    }
+
+    /// Render big updates in slices of at most `budget_ms` milliseconds, yielding to the browser between them.
+    ///
+    /// Normally every update is rendered in one go, which can block input and painting for a while if a lot of
+    /// components have to re-render. With a time slice, Dioxus stops rendering once the budget is used up, applies what
+    /// it has, and lets the browser handle events before rendering the rest. Events that arrive in between are handled
+    /// before the next slice.
+    ///
+    /// A single component always renders in one go, so a slice can run over the budget. This is off by default.
+    pub fn time_slice(mut self, budget_ms: f64) -> Self {
+        self.time_slice = Some(budget_ms);
+        self
+    }
 }
@@ -0,0 +1,52 @@
+//! Reading the clipboard outside of a paste event
+
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::JsFuture;
+
+// web-sys only has bindings for the async clipboard api when it's built with the unstable apis enabled
+#[wasm_bindgen]
+extern "C" {
+    type Clipboard;
+
+    #[wasm_bindgen(method, catch, js_name = readText)]
+    fn read_text(this: &Clipboard) -> Result<js_sys::Promise, JsValue>;
+}
+
+/// Read the text on the clipboard, like a paste event would but without the user pressing Ctrl+V.
+///
+/// Browsers ask the user for permission the first time a page reads the clipboard, and most of them only allow it in
+/// response to a click or key press. Returns `None` if the user denied the permission, the page isn't a secure
+/// context, the browser doesn't support reading the clipboard, or the clipboard doesn't hold any text.
+///
+/// ```rust, ignore
+/// button {
+///     onclick: move |_| {
+///         let text = text.clone();
+///         cx.spawn(async move {
+///             if let Some(pasted) = read_clipboard_text().await {
+///                 text.set(pasted);
+///             }
+///         });
+///     },
+///     "Paste"
+/// }
+/// ```
+pub async fn read_clipboard_text() -> Option<String> {
+    let navigator = web_sys::window()?.navigator();
+
+    // navigator.clipboard is undefined in insecure contexts and older browsers
+    let clipboard = js_sys::Reflect::get(&navigator, &JsValue::from_str("clipboard")).ok()?;
+    if clipboard.is_undefined() || clipboard.is_null() {
+        return None;
+    }
+    let clipboard: Clipboard = clipboard.unchecked_into();
+
+    let read = clipboard.read_text().ok()?;
+    match JsFuture::from(read).await {
+        Ok(text) => text.as_string(),
+        Err(err) => {
+            log::debug!("Couldn't read the clipboard: {:?}", err);
+            None
+        }
+    }
+}
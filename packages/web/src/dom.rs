@@ -1,24 +1,32 @@
 //! Implementation of a renderer for Dioxus on the web.
 //!
 //! Oustanding todos:
-//! - Removing event listeners (delegation)
-//! - Passive event listeners
-//! - no-op event listener patch for safari
 //! - tests to ensure dyn_into works for various event types.
 //! - Partial delegation?>
 
 use dioxus_core::{
     BorrowedAttributeValue, ElementId, Mutation, Template, TemplateAttribute, TemplateNode,
 };
-use dioxus_html::{event_bubbles, CompositionData, FormData};
+use dioxus_html::{
+    capture_event, event_bubbles, CompositionData, CustomData, FormData, HistoryData, MountedData,
+    ToggleData,
+};
 use dioxus_interpreter_js::{save_template, Channel};
 use futures_channel::mpsc;
 use rustc_hash::FxHashMap;
-use std::{any::Any, rc::Rc};
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 use wasm_bindgen::{closure::Closure, JsCast};
-use web_sys::{Document, Element, Event, HtmlElement};
+use web_sys::{Document, Element, Event, EventTarget, HtmlElement};
 
-use crate::Config;
+use crate::{
+    observer::{SizeObserver, VisibilityObserver},
+    Config, GlobalTarget,
+};
 
 pub struct WebsysDom {
     document: Document,
@@ -27,6 +35,13 @@ pub struct WebsysDom {
     templates: FxHashMap<String, u32>,
     max_template_id: u32,
     pub(crate) interpreter: Channel,
+    global_listeners: GlobalListeners,
+    event_channel: mpsc::UnboundedSender<UiEvent>,
+
+    // Elements with an onmounted listener that were created since the last edits were applied
+    pub(crate) queued_mounted_events: Vec<ElementId>,
+    pub(crate) visibility: VisibilityObserver,
+    pub(crate) sizes: SizeObserver,
 }
 
 pub struct UiEvent {
@@ -37,16 +52,45 @@ pub struct UiEvent {
     pub event: Event,
 }
 
+/// Send an event that the renderer fires itself, instead of the browser, to a single element
+pub(crate) fn send_event(
+    event_channel: &mpsc::UnboundedSender<UiEvent>,
+    name: &str,
+    element: ElementId,
+    data: Rc<dyn Any>,
+) {
+    if let Ok(event) = Event::new(name) {
+        let _ = event_channel.unbounded_send(UiEvent {
+            name: name.to_string(),
+            bubbles: false,
+            element,
+            data,
+            event,
+        });
+    }
+}
+
 impl WebsysDom {
     pub fn new(cfg: Config, event_channel: mpsc::UnboundedSender<UiEvent>) -> Self {
         // eventually, we just want to let the interpreter do all the work of decoding events into our event type
         // a match here in order to avoid some error during runtime browser test
         let document = load_document();
-        let root = match document.get_element_by_id(&cfg.rootname) {
+        let root = match cfg.root {
             Some(root) => root,
-            None => document.create_element("body").ok().unwrap(),
+            None => match document.get_element_by_id(&cfg.rootname) {
+                Some(root) => root,
+                None => panic!(
+                    "Couldn't find an element with the id {:?} to mount the app into. Add it to your index.html, or pass the element with `Config::with_root_element`",
+                    cfg.rootname
+                ),
+            },
         };
         let interpreter = Channel::default();
+        let global_listeners = GlobalListeners::new(document.clone(), event_channel.clone());
+        let mounted_event_channel = event_channel.clone();
+        let visibility = VisibilityObserver::new(event_channel.clone());
+        let sizes = SizeObserver::new(event_channel.clone());
+        let always_passive = cfg.default_passive_events;
 
         let handler: Closure<dyn FnMut(&Event)> =
             Closure::wrap(Box::new(move |event: &web_sys::Event| {
@@ -60,10 +104,15 @@ impl WebsysDom {
                         .map(|f| f.trim_start_matches("on"))
                         == Some(&name)
                     {
-                        event.prevent_default();
+                        if always_passive && is_scroll_blocking(&name) {
+                            // The listener is passive, so the browser would ignore us anyway
+                            log::debug!("Ignoring prevent_default for passive {} listeners", name);
+                        } else {
+                            event.prevent_default();
+                        }
                     }
 
-                    let data = virtual_event_from_websys_event(event.clone(), target);
+                    let data = virtual_event_from_websys_event(&name, event, target);
                     let _ = event_channel.unbounded_send(UiEvent {
                         name,
                         bubbles,
@@ -74,6 +123,7 @@ impl WebsysDom {
                 }
             }));
 
+        dioxus_interpreter_js::set_always_passive(always_passive);
         dioxus_interpreter_js::initilize(
             root.clone().unchecked_into(),
             handler.as_ref().unchecked_ref(),
@@ -85,6 +135,11 @@ impl WebsysDom {
             interpreter,
             templates: FxHashMap::default(),
             max_template_id: 0,
+            global_listeners,
+            event_channel: mounted_event_channel,
+            queued_mounted_events: Vec::new(),
+            visibility,
+            sizes,
         }
     }
 
@@ -97,7 +152,7 @@ impl WebsysDom {
             let mut roots = vec![];
 
             for root in template.roots {
-                roots.push(self.create_template_node(root))
+                roots.push(self.create_template_node(root, None))
             }
 
             self.templates
@@ -107,7 +162,11 @@ impl WebsysDom {
         }
     }
 
-    fn create_template_node(&self, v: &TemplateNode) -> web_sys::Node {
+    fn create_template_node(
+        &self,
+        v: &TemplateNode,
+        parent_namespace: Option<&'static str>,
+    ) -> web_sys::Node {
         use TemplateNode::*;
         match v {
             Element {
@@ -117,6 +176,7 @@ impl WebsysDom {
                 children,
                 ..
             } => {
+                let namespace = element_namespace(tag, *namespace, parent_namespace);
                 let el = match namespace {
                     Some(ns) => self.document.create_element_ns(Some(ns), tag).unwrap(),
                     None => self.document.create_element(tag).unwrap(),
@@ -138,8 +198,13 @@ impl WebsysDom {
                         }
                     }
                 }
+                // Children of a foreignObject are back in the html namespace
+                let child_namespace = match *tag {
+                    "foreignObject" => None,
+                    _ => namespace,
+                };
                 for child in *children {
-                    let _ = el.append_child(&self.create_template_node(child));
+                    let _ = el.append_child(&self.create_template_node(child, child_namespace));
                 }
                 el.dyn_into().unwrap()
             }
@@ -155,8 +220,8 @@ impl WebsysDom {
 
     pub fn apply_edits(&mut self, mut edits: Vec<Mutation>) {
         use Mutation::*;
-        let i = &mut self.interpreter;
         for edit in &edits {
+            let i = &mut self.interpreter;
             match edit {
                 AppendChildren { id, m } => i.append_children(id.0 as u32, *m as u32),
                 AssignId { path, id } => {
@@ -205,51 +270,270 @@ impl WebsysDom {
                     _ => unreachable!(),
                 },
                 SetText { value, id } => i.set_text(id.0 as u32, value),
-                NewEventListener { name, id, .. } => {
-                    i.new_event_listener(name, id.0 as u32, event_bubbles(name) as u8);
-                }
-                RemoveEventListener { name, id } => {
-                    i.remove_event_listener(name, id.0 as u32, event_bubbles(name) as u8)
-                }
+                NewEventListener { name, id, .. } => self.add_listener(name, *id),
+                RemoveEventListener { name, id } => self.remove_listener(name, *id),
                 Remove { id } => i.remove(id.0 as u32),
                 PushRoot { id } => i.push_root(id.0 as u32),
             }
         }
         edits.clear();
-        i.flush();
+        self.interpreter.flush();
+        crate::portal::remove_orphaned_nodes();
+
+        self.flush_queued_listeners();
+    }
+
+    /// Start delivering an event to an element, listening for it wherever the browser dispatches it
+    pub(crate) fn add_listener(&mut self, name: &str, id: ElementId) {
+        // Core calls capture listeners itself, so they only need the event delivered like any other
+        let name = capture_event(name).unwrap_or(name);
+        if name == "mounted" {
+            self.queued_mounted_events.push(id);
+        } else if name == "visible" {
+            self.visibility.queue(id);
+        } else if name == "resize" {
+            self.sizes.queue(id);
+        } else if global_event_target(name).is_some() {
+            self.global_listeners.add(name, id);
+        } else {
+            self.interpreter
+                .new_event_listener(name, id.0 as u32, event_bubbles(name) as u8);
+        }
+    }
+
+    pub(crate) fn remove_listener(&mut self, name: &str, id: ElementId) {
+        let name = capture_event(name).unwrap_or(name);
+        if name == "mounted" {
+            // The mounted event was already sent, there's no listener in the dom to remove
+        } else if name == "visible" {
+            self.visibility.remove(id);
+        } else if name == "resize" {
+            self.sizes.remove(id);
+        } else if global_event_target(name).is_some() {
+            self.global_listeners.remove(name, id);
+        } else {
+            self.interpreter
+                .remove_event_listener(name, id.0 as u32, event_bubbles(name) as u8)
+        }
+    }
+
+    /// Handle the listeners that need their element to exist in the dom, which it does once the edits have been
+    /// flushed. This fires the mounted event of every element with an onmounted listener created since the last time
+    /// this was called, and watches new elements with an onvisible or onresize listener.
+    pub(crate) fn flush_queued_listeners(&mut self) {
+        self.visibility.flush();
+        self.sizes.flush();
+
+        for id in self.queued_mounted_events.drain(..) {
+            let data = Rc::new(MountedData::new(id));
+            send_event(&self.event_channel, "mounted", id, data);
+        }
+    }
+}
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+/// The namespace to create an element in.
+///
+/// Only elements whose definition names a namespace carry one in their template, so elements without one (like `a`
+/// or custom elements) inherit the namespace of their parent. Otherwise they end up as html elements inside an svg and
+/// never render.
+fn element_namespace(
+    tag: &str,
+    namespace: Option<&'static str>,
+    parent_namespace: Option<&'static str>,
+) -> Option<&'static str> {
+    namespace.or(match tag {
+        "svg" => Some(SVG_NAMESPACE),
+        "math" => Some(MATHML_NAMESPACE),
+        _ => parent_namespace,
+    })
+}
+
+/// The browser has to wait for active listeners of these events before it can scroll
+fn is_scroll_blocking(name: &str) -> bool {
+    matches!(name, "touchstart" | "touchmove" | "wheel")
+}
+
+/// Where an event that never reaches the root element is dispatched
+fn global_event_target(name: &str) -> Option<GlobalTarget> {
+    match name {
+        "securitypolicyviolation" => Some(GlobalTarget::Document),
+        "popstate" | "hashchange" => Some(GlobalTarget::Window),
+        _ => None,
+    }
+}
+
+/// Listeners for events that are dispatched on the document or window instead of an element.
+///
+/// Delegation at the root can't see these events, so we listen for them once per event name at their target and
+/// forward every occurrence to each element that registered a listener for it.
+struct GlobalListeners {
+    document: Document,
+    event_channel: mpsc::UnboundedSender<UiEvent>,
+    listeners: FxHashMap<String, GlobalListener>,
+}
+
+struct GlobalListener {
+    name: String,
+    target: EventTarget,
+    elements: Rc<RefCell<Vec<ElementId>>>,
+    callback: Closure<dyn FnMut(&Event)>,
+}
+
+impl GlobalListeners {
+    fn new(document: Document, event_channel: mpsc::UnboundedSender<UiEvent>) -> Self {
+        Self {
+            document,
+            event_channel,
+            listeners: FxHashMap::default(),
+        }
+    }
+
+    fn add(&mut self, name: &str, element: ElementId) {
+        if let Some(listener) = self.listeners.get(name) {
+            let mut elements = listener.elements.borrow_mut();
+            if !elements.contains(&element) {
+                elements.push(element);
+            }
+            return;
+        }
+
+        let target: EventTarget = match global_event_target(name) {
+            Some(GlobalTarget::Window) => match web_sys::window() {
+                Some(window) => window.unchecked_into(),
+                None => return,
+            },
+            Some(GlobalTarget::Document) | None => self.document.clone().unchecked_into(),
+        };
+
+        let elements = Rc::new(RefCell::new(vec![element]));
+        let callback: Closure<dyn FnMut(&Event)> = {
+            let elements = elements.clone();
+            let event_channel = self.event_channel.clone();
+            let document = self.document.clone();
+            let name = name.to_string();
+            Closure::wrap(Box::new(move |event: &Event| {
+                let target = match event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .or_else(|| document.document_element())
+                {
+                    Some(target) => target,
+                    None => return,
+                };
+
+                let data = virtual_event_from_websys_event(&name, event, target);
+                for element in elements.borrow().iter() {
+                    let _ = event_channel.unbounded_send(UiEvent {
+                        name: name.clone(),
+                        bubbles: false,
+                        element: *element,
+                        data: data.clone(),
+                        event: event.clone(),
+                    });
+                }
+            }))
+        };
+
+        let _ = target.add_event_listener_with_callback(name, callback.as_ref().unchecked_ref());
+
+        self.listeners.insert(
+            name.to_string(),
+            GlobalListener {
+                name: name.to_string(),
+                target,
+                elements,
+                callback,
+            },
+        );
+    }
+
+    fn remove(&mut self, name: &str, element: ElementId) {
+        let empty = match self.listeners.get(name) {
+            Some(listener) => {
+                let mut elements = listener.elements.borrow_mut();
+                elements.retain(|id| *id != element);
+                elements.is_empty()
+            }
+            None => false,
+        };
+
+        if empty {
+            self.listeners.remove(name);
+        }
+    }
+}
+
+impl Drop for GlobalListener {
+    fn drop(&mut self) {
+        let _ = self.target.remove_event_listener_with_callback(
+            &self.name,
+            self.callback.as_ref().unchecked_ref(),
+        );
     }
 }
 
 // todo: some of these events are being casted to the wrong event type.
 // We need tests that simulate clicks/etc and make sure every event type works.
-pub fn virtual_event_from_websys_event(event: web_sys::Event, target: Element) -> Rc<dyn Any> {
+/// Decode the data of an event with the given name.
+///
+/// Reading the name of an event copies it out of javascript, so the caller passes in the name it already read instead
+/// of reading it again for every event.
+pub fn virtual_event_from_websys_event(
+    name: &str,
+    event: &web_sys::Event,
+    target: Element,
+) -> Rc<dyn Any> {
     use dioxus_html::events::*;
 
-    match event.type_().as_str() {
-        "copy" | "cut" | "paste" => Rc::new(ClipboardData {}),
+    match name {
+        #[cfg(feature = "clipboard-events")]
+        "copy" | "cut" | "paste" => make_clipboard_event(event),
         "compositionend" | "compositionstart" | "compositionupdate" => {
-            make_composition_event(&event)
+            make_composition_event(event)
         }
         "keydown" | "keypress" | "keyup" => Rc::new(KeyboardData::from(event)),
-        "focus" | "blur" | "focusout" | "focusin" => Rc::new(FocusData {}),
+        "focus" | "blur" | "focusout" | "focusin" => Rc::new(make_focus_event(event)),
 
         "change" | "input" | "invalid" | "reset" | "submit" => read_input_to_data(target),
+        "beforeinput" => Rc::new(make_before_input_event(event)),
 
         "click" | "contextmenu" | "dblclick" | "doubleclick" | "mousedown" | "mouseenter"
         | "mouseleave" | "mousemove" | "mouseout" | "mouseover" | "mouseup" => {
             Rc::new(MouseData::from(event))
         }
+        #[cfg(feature = "drag-events")]
         "drag" | "dragend" | "dragenter" | "dragexit" | "dragleave" | "dragover" | "dragstart"
         | "drop" => {
+            let data_transfer = event
+                .dyn_ref::<web_sys::DragEvent>()
+                .and_then(|evt| evt.data_transfer());
+            let (types, data) = data_transfer
+                .as_ref()
+                .map(read_data_transfer)
+                .unwrap_or_default();
+            let files = data_transfer
+                .and_then(|data_transfer| crate::file_engine::WebFileEngine::new(&data_transfer))
+                .map(|engine| std::sync::Arc::new(engine) as std::sync::Arc<dyn FileEngine>);
             let mouse = MouseData::from(event);
-            Rc::new(DragData { mouse })
+            Rc::new(DragData {
+                mouse,
+                types,
+                data,
+                files,
+            })
         }
 
+        #[cfg(feature = "pointer-events")]
         "pointerdown" | "pointermove" | "pointerup" | "pointercancel" | "gotpointercapture"
         | "lostpointercapture" | "pointerenter" | "pointerleave" | "pointerover" | "pointerout" => {
             Rc::new(PointerData::from(event))
         }
-        "select" => Rc::new(SelectionData {}),
+        #[cfg(feature = "pointer-raw-update")]
+        "pointerrawupdate" => Rc::new(PointerData::from(event)),
+        "select" => Rc::new(make_selection_event(&target)),
         "touchcancel" | "touchend" | "touchmove" | "touchstart" => Rc::new(TouchData::from(event)),
 
         "scroll" => Rc::new(()),
@@ -258,21 +542,278 @@ pub fn virtual_event_from_websys_event(event: web_sys::Event, target: Element) -
             Rc::new(AnimationData::from(event))
         }
         "transitionend" => Rc::new(TransitionData::from(event)),
+        #[cfg(feature = "media-events")]
         "abort" | "canplay" | "canplaythrough" | "durationchange" | "emptied" | "encrypted"
         | "ended" | "error" | "loadeddata" | "loadedmetadata" | "loadstart" | "pause" | "play"
         | "playing" | "progress" | "ratechange" | "seeked" | "seeking" | "stalled" | "suspend"
-        | "timeupdate" | "volumechange" | "waiting" => Rc::new(MediaData {}),
-        "toggle" => Rc::new(ToggleData {}),
+        | "timeupdate" | "volumechange" | "waiting" => Rc::new(make_media_event(&target)),
+        "toggle" | "beforetoggle" => Rc::new(make_toggle_event(event, &target)),
+        "securitypolicyviolation" => Rc::new(SecurityPolicyViolationData::from(event)),
+        "popstate" | "hashchange" => Rc::new(make_history_event(event)),
 
-        _ => Rc::new(()),
+        // Unknown events, and events whose decoding was compiled out, can't be decoded into the data their listeners expect
+        other => match event.dyn_ref::<web_sys::CustomEvent>() {
+            Some(custom) => Rc::new(make_custom_event(custom)),
+            None => {
+                warn_unsupported_event(other);
+                Rc::new(())
+            }
+        },
     }
 }
 
+/// Warn about an event we can't decode the first time it's dispatched, since some of them fire many times a second
+fn warn_unsupported_event(name: &str) {
+    thread_local! {
+        static WARNED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+    }
+
+    let first = WARNED.with(|warned| {
+        let mut warned = warned.borrow_mut();
+        !warned.contains(name) && warned.insert(name.to_string())
+    });
+    if !first {
+        return;
+    }
+
+    match compiled_out_feature(name) {
+        Some(feature) => log::warn!(
+            "The {:?} event is only decoded with the `{}` feature of dioxus-web, its listeners won't be called",
+            name,
+            feature
+        ),
+        None => log::warn!(
+            "Unsupported event type {:?}, its listeners won't be called",
+            name
+        ),
+    }
+}
+
+/// The feature that decodes an event, if it was disabled when compiling
+fn compiled_out_feature(name: &str) -> Option<&'static str> {
+    match name {
+        #[cfg(not(feature = "clipboard-events"))]
+        "copy" | "cut" | "paste" => Some("clipboard-events"),
+        #[cfg(not(feature = "drag-events"))]
+        "drag" | "dragend" | "dragenter" | "dragexit" | "dragleave" | "dragover" | "dragstart"
+        | "drop" => Some("drag-events"),
+        #[cfg(not(feature = "pointer-events"))]
+        "pointerdown" | "pointermove" | "pointerup" | "pointercancel" | "gotpointercapture"
+        | "lostpointercapture" | "pointerenter" | "pointerleave" | "pointerover" | "pointerout" => {
+            Some("pointer-events")
+        }
+        #[cfg(not(feature = "pointer-raw-update"))]
+        "pointerrawupdate" => Some("pointer-raw-update"),
+        #[cfg(not(feature = "media-events"))]
+        "abort" | "canplay" | "canplaythrough" | "durationchange" | "emptied" | "encrypted"
+        | "ended" | "error" | "loadeddata" | "loadedmetadata" | "loadstart" | "pause" | "play"
+        | "playing" | "progress" | "ratechange" | "seeked" | "seeking" | "stalled" | "suspend"
+        | "timeupdate" | "volumechange" | "waiting" => Some("media-events"),
+        _ => None,
+    }
+}
+
+fn make_custom_event(event: &web_sys::CustomEvent) -> CustomData {
+    let detail = event.detail();
+    let detail = match detail.is_undefined() || detail.is_null() {
+        true => None,
+        false => js_sys::JSON::stringify(&detail)
+            .ok()
+            .and_then(|json| json.as_string()),
+    };
+
+    CustomData { detail }
+}
+
 fn make_composition_event(event: &Event) -> Rc<CompositionData> {
-    let evt: &web_sys::CompositionEvent = event.dyn_ref().unwrap();
-    Rc::new(CompositionData {
-        data: evt.data().unwrap_or_default(),
-    })
+    // Custom events can reuse the name of a composition event, so don't assume the type
+    let data = event
+        .dyn_ref::<web_sys::CompositionEvent>()
+        .and_then(|evt| evt.data())
+        .unwrap_or_default();
+    Rc::new(CompositionData { data })
+}
+
+#[cfg(feature = "clipboard-events")]
+fn make_clipboard_event(event: &Event) -> Rc<dioxus_html::ClipboardData> {
+    use dioxus_html::{ClipboardData, FileEngine};
+
+    let data_transfer = event
+        .dyn_ref::<web_sys::ClipboardEvent>()
+        .and_then(|evt| evt.clipboard_data());
+
+    // A paste can hold text and files at the same time, so read both
+    let text = data_transfer
+        .as_ref()
+        .and_then(|data_transfer| data_transfer.get_data("text/plain").ok())
+        .filter(|text| !text.is_empty());
+    let files = data_transfer
+        .and_then(|data_transfer| crate::file_engine::WebFileEngine::new(&data_transfer))
+        .map(|engine| std::sync::Arc::new(engine) as std::sync::Arc<dyn FileEngine>);
+
+    Rc::new(ClipboardData { text, files })
+}
+
+#[cfg(feature = "media-events")]
+fn make_media_event(target: &Element) -> dioxus_html::MediaData {
+    // Media events are delegated like every other event, so the target isn't guaranteed to be a media element
+    let media = match target.dyn_ref::<web_sys::HtmlMediaElement>() {
+        Some(media) => media,
+        None => return Default::default(),
+    };
+
+    let duration = media.duration();
+    dioxus_html::MediaData {
+        current_time: media.current_time(),
+        duration: if duration.is_finite() { duration } else { 0.0 },
+        volume: media.volume(),
+        muted: media.muted(),
+        paused: media.paused(),
+        playback_rate: media.playback_rate(),
+    }
+}
+
+fn make_focus_event(event: &Event) -> dioxus_html::FocusData {
+    // The related target is null when focus moves in from outside the document
+    let related_target = event
+        .dyn_ref::<web_sys::FocusEvent>()
+        .and_then(|evt| evt.related_target())
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .and_then(|element| element.get_attribute("data-dioxus-id"))
+        .and_then(|id| id.parse().ok())
+        .map(ElementId);
+
+    dioxus_html::FocusData { related_target }
+}
+
+fn make_selection_event(target: &Element) -> dioxus_html::SelectionData {
+    let range = if let Some(input) = target.dyn_ref::<web_sys::HtmlInputElement>() {
+        // Inputs like checkboxes don't support selection, and throw if you ask for it
+        let start = input.selection_start().ok().flatten();
+        let end = input.selection_end().ok().flatten();
+        Some((input.value(), start, end))
+    } else {
+        target
+            .dyn_ref::<web_sys::HtmlTextAreaElement>()
+            .map(|textarea| {
+                let start = textarea.selection_start().ok().flatten();
+                let end = textarea.selection_end().ok().flatten();
+                (textarea.value(), start, end)
+            })
+    };
+
+    match range {
+        Some((value, start, end)) => {
+            // The selection is measured in UTF-16 code units, so slice it the same way
+            let text = start.zip(end).map(|(start, end)| {
+                let units: Vec<u16> = value.encode_utf16().collect();
+                let start = (start as usize).min(units.len());
+                let end = (end as usize).clamp(start, units.len());
+                String::from_utf16_lossy(&units[start..end])
+            });
+
+            dioxus_html::SelectionData { start, end, text }
+        }
+
+        // Anything else has to be contenteditable, which is covered by the selection of the document
+        None => dioxus_html::SelectionData {
+            start: None,
+            end: None,
+            text: web_sys::window()
+                .and_then(|window| window.get_selection().ok().flatten())
+                .and_then(|selection| selection.to_string().as_string()),
+        },
+    }
+}
+
+fn make_before_input_event(event: &Event) -> dioxus_html::BeforeInputData {
+    match event.dyn_ref::<web_sys::InputEvent>() {
+        Some(evt) => dioxus_html::BeforeInputData {
+            input_type: evt.input_type(),
+            data: evt.data(),
+        },
+        None => Default::default(),
+    }
+}
+
+/// Read the formats and string data out of a data transfer while the event is still being dispatched
+#[cfg(feature = "drag-events")]
+fn read_data_transfer(
+    data_transfer: &web_sys::DataTransfer,
+) -> (Vec<String>, std::collections::HashMap<String, String>) {
+    let types: Vec<String> = data_transfer
+        .types()
+        .iter()
+        .filter_map(|ty| ty.as_string())
+        .collect();
+
+    // Files aren't string data, and reading the data of a protected transfer gives an empty string
+    let data = types
+        .iter()
+        .filter(|ty| *ty != "Files")
+        .filter_map(|ty| {
+            let value = data_transfer.get_data(ty).ok()?;
+            (!value.is_empty()).then(|| (ty.clone(), value))
+        })
+        .collect();
+
+    (types, data)
+}
+
+fn make_toggle_event(event: &Event, target: &Element) -> ToggleData {
+    // ToggleEvent is only available in web-sys behind the unstable apis flag, so read its fields directly
+    let state = |name: &str| {
+        js_sys::Reflect::get(event, &name.into())
+            .ok()
+            .and_then(|state| state.as_string())
+            .unwrap_or_default()
+    };
+
+    let new_state = state("newState");
+
+    // Popovers don't have an open property, but they do report their new state
+    let open = match target.dyn_ref::<web_sys::HtmlDetailsElement>() {
+        Some(details) => details.open(),
+        None if !new_state.is_empty() => new_state == "open",
+        None => {
+            log::debug!("Toggle event target is not a details element, assuming it is closed");
+            false
+        }
+    };
+
+    ToggleData {
+        old_state: state("oldState"),
+        new_state,
+        open,
+    }
+}
+
+fn make_history_event(event: &web_sys::Event) -> HistoryData {
+    let window = match web_sys::window() {
+        Some(window) => window,
+        None => return HistoryData::default(),
+    };
+
+    let location = window.location();
+
+    // The state of a popstate event is the same as the state of the history entry we just moved to
+    let state = window
+        .history()
+        .ok()
+        .and_then(|history| history.state().ok())
+        .filter(|state| !state.is_null() && !state.is_undefined())
+        .and_then(|state| js_sys::JSON::stringify(&state).ok())
+        .and_then(|state| state.as_string());
+
+    let hash_change = event.dyn_ref::<web_sys::HashChangeEvent>();
+
+    HistoryData {
+        pathname: location.pathname().unwrap_or_default(),
+        hash: location.hash().unwrap_or_default(),
+        state,
+        old_url: hash_change.map(|event| event.old_url()),
+        new_url: hash_change.map(|event| event.new_url()),
+    }
 }
 
 pub(crate) fn load_document() -> Document {
@@ -291,12 +832,7 @@ fn read_input_to_data(target: Element) -> Rc<FormData> {
         .map(|input: &web_sys::HtmlInputElement| {
             // todo: special case more input types
             match input.type_().as_str() {
-                "checkbox" => {
-                    match input.checked() {
-                        true => "true".to_string(),
-                        false => "false".to_string(),
-                    }
-                },
+                "checkbox" => checkbox_value(input).to_string(),
                 _ => {
                     input.value()
                 }
@@ -321,52 +857,142 @@ fn read_input_to_data(target: Element) -> Rc<FormData> {
         })
         .expect("only an InputElement or TextAreaElement or an element with contenteditable=true can have an oninput event listener");
 
-    let mut values = std::collections::HashMap::new();
-
-    // try to fill in form values
-    if let Some(form) = target.dyn_ref::<web_sys::HtmlFormElement>() {
-        let elements = form.elements();
-        for x in 0..elements.length() {
-            let element = elements.item(x).unwrap();
-            if let Some(name) = element.get_attribute("name") {
-                let value: Option<String> = element
-                    .dyn_ref()
-                    .map(|input: &web_sys::HtmlInputElement| {
-                        match input.type_().as_str() {
-                            "checkbox" => {
-                                match input.checked() {
-                                    true => Some("true".to_string()),
-                                    false => Some("false".to_string()),
-                                }
-                            },
-                            "radio" => {
-                                match input.checked() {
-                                    true => Some(input.value()),
-                                    false => None,
-                                }
-                            }
-                            _ => Some(input.value())
-                        }
-                    })
-                    .or_else(|| element.dyn_ref().map(|input: &web_sys::HtmlTextAreaElement| Some(input.value())))
-                    .or_else(|| element.dyn_ref().map(|input: &web_sys::HtmlSelectElement| Some(input.value())))
-                    .or_else(|| Some(element.dyn_ref::<web_sys::HtmlElement>().unwrap().text_content()))
-                    .expect("only an InputElement or TextAreaElement or an element with contenteditable=true can have an oninput event listener");
-                if let Some(value) = value {
-                    values.insert(name, value);
-                }
-            }
-        }
-    }
+    // Anything other than a form control has to be contenteditable, so keep its markup around for rich text editors
+    let is_form_control = target.dyn_ref::<web_sys::HtmlInputElement>().is_some()
+        || target.dyn_ref::<web_sys::HtmlTextAreaElement>().is_some()
+        || target.dyn_ref::<web_sys::HtmlSelectElement>().is_some()
+        || target.dyn_ref::<web_sys::HtmlFormElement>().is_some();
+    let html = (!is_form_control).then(|| target.inner_html());
+
+    // Inputs that are empty or aren't numeric have a NaN value
+    let value_as_number = target
+        .dyn_ref::<web_sys::HtmlInputElement>()
+        .map(|input| input.value_as_number())
+        .filter(|number| !number.is_nan());
+
+    let selected = target
+        .dyn_ref::<web_sys::HtmlSelectElement>()
+        .map(selected_options)
+        .unwrap_or_default();
+
+    let files = target
+        .dyn_ref::<web_sys::HtmlInputElement>()
+        .filter(|input| input.type_() == "file")
+        .and_then(crate::file_engine::WebFileEngine::from_input)
+        .map(|engine| std::sync::Arc::new(engine) as std::sync::Arc<dyn dioxus_html::FileEngine>);
+
+    let (values, selected_values) = match target.dyn_ref::<web_sys::HtmlFormElement>() {
+        Some(form) => read_form_values(form),
+        None => Default::default(),
+    };
 
     Rc::new(FormData {
         value,
         values,
-        files: None,
+        selected,
+        selected_values,
+        value_as_number,
+        html,
+        files,
     })
 }
 
+thread_local! {
+    // How many values each form had the last time we read it, keyed by its dioxus id. Reading a big form on every
+    // keystroke would otherwise grow its maps from empty each time. The values themselves are always read from the dom,
+    // since scripts and the browser can change a field without an event we listen to.
+    static FORM_SIZES: RefCell<FxHashMap<String, (usize, usize)>> = RefCell::new(FxHashMap::default());
+}
+
+fn read_form_values(
+    form: &web_sys::HtmlFormElement,
+) -> (HashMap<String, String>, HashMap<String, Vec<String>>) {
+    let id = form.get_attribute("data-dioxus-id").unwrap_or_default();
+    let (values_len, selected_len) = FORM_SIZES
+        .with(|sizes| sizes.borrow().get(&id).copied())
+        .unwrap_or_default();
+
+    let mut values = HashMap::with_capacity(values_len);
+    let mut selected_values = HashMap::with_capacity(selected_len);
+
+    let elements = form.elements();
+    for x in 0..elements.length() {
+        let element = elements.item(x).unwrap();
+        if let Some(name) = element.get_attribute("name") {
+            if let Some(select) = element.dyn_ref::<web_sys::HtmlSelectElement>() {
+                if select.multiple() {
+                    selected_values.insert(name.clone(), selected_options(select));
+                }
+            }
+            if let Some(value) = read_field_value(&element) {
+                values.insert(name, value);
+            }
+        }
+    }
+
+    FORM_SIZES.with(|sizes| {
+        sizes
+            .borrow_mut()
+            .insert(id, (values.len(), selected_values.len()))
+    });
+
+    (values, selected_values)
+}
+
+/// The value a field contributes to the values of its form, if any
+fn read_field_value(element: &Element) -> Option<String> {
+    element
+        .dyn_ref()
+        .map(|input: &web_sys::HtmlInputElement| {
+            match input.type_().as_str() {
+                "checkbox" => Some(checkbox_value(input).to_string()),
+                "radio" => {
+                    match input.checked() {
+                        true => Some(input.value()),
+                        false => None,
+                    }
+                }
+                _ => Some(input.value())
+            }
+        })
+        .or_else(|| element.dyn_ref().map(|input: &web_sys::HtmlTextAreaElement| Some(input.value())))
+        .or_else(|| element.dyn_ref().map(|input: &web_sys::HtmlSelectElement| Some(input.value())))
+        .or_else(|| Some(element.dyn_ref::<web_sys::HtmlElement>().unwrap().text_content()))
+        .expect("only an InputElement or TextAreaElement or an element with contenteditable=true can have an oninput event listener")
+}
+
+/// Checkboxes can be in a third, indeterminate state, which is common for "select all" checkboxes
+fn checkbox_value(input: &web_sys::HtmlInputElement) -> &'static str {
+    if input.indeterminate() {
+        "indeterminate"
+    } else if input.checked() {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+/// Every selected value of a `<select multiple>`, or nothing for a single select
+fn selected_options(select: &web_sys::HtmlSelectElement) -> Vec<String> {
+    if !select.multiple() {
+        return Vec::new();
+    }
+
+    let options = select.selected_options();
+    (0..options.length())
+        .filter_map(|idx| options.item(idx))
+        .filter_map(|option| option.dyn_into::<web_sys::HtmlOptionElement>().ok())
+        .map(|option| option.value())
+        .collect()
+}
+
 fn walk_event_for_id(event: &web_sys::Event) -> Option<(ElementId, web_sys::Element)> {
+    // Events that come from inside a shadow root are retargeted to the shadow host, so walking up from the target can
+    // skip over the elements we rendered. The composed path keeps every node the event passed through, innermost first.
+    if let Some(found) = walk_composed_path_for_id(event) {
+        return found;
+    }
+
     let mut target = event
         .target()
         .expect("missing target")
@@ -374,9 +1000,8 @@ fn walk_event_for_id(event: &web_sys::Event) -> Option<(ElementId, web_sys::Elem
         .expect("not a valid element");
 
     loop {
-        match target.get_attribute("data-dioxus-id").map(|f| f.parse()) {
-            Some(Ok(id)) => return Some((ElementId(id), target)),
-            Some(Err(_)) => return None,
+        match target.get_attribute("data-dioxus-id") {
+            Some(id) => return parse_dioxus_id(&id).map(|id| (id, target)),
 
             // walk the tree upwards until we actually find an event target
             None => match target.parent_element() {
@@ -386,3 +1011,53 @@ fn walk_event_for_id(event: &web_sys::Event) -> Option<(ElementId, web_sys::Elem
         }
     }
 }
+
+/// Find the innermost element in the composed path of the event that has a dioxus id.
+///
+/// Returns `None` if the browser doesn't support `composedPath` or the path is empty, in which case we fall back to
+/// walking up the parents of the target.
+fn walk_composed_path_for_id(
+    event: &web_sys::Event,
+) -> Option<Option<(ElementId, web_sys::Element)>> {
+    let supported = js_sys::Reflect::has(event, &wasm_bindgen::JsValue::from_str("composedPath"))
+        .unwrap_or(false);
+    if !supported {
+        return None;
+    }
+
+    let path = event.composed_path();
+    if path.length() == 0 {
+        return None;
+    }
+
+    for node in path.iter() {
+        let element = match node.dyn_into::<web_sys::Element>() {
+            Ok(element) => element,
+            Err(_) => continue,
+        };
+
+        if let Some(id) = element.get_attribute("data-dioxus-id") {
+            return Some(parse_dioxus_id(&id).map(|id| (id, element)));
+        }
+    }
+
+    Some(None)
+}
+
+/// Parse the `data-dioxus-id` attribute of the element an event is for.
+///
+/// The event can't be delivered without an id, so a corrupted attribute is logged instead of silently dropping every
+/// event on the element.
+fn parse_dioxus_id(id: &str) -> Option<ElementId> {
+    match id.parse() {
+        Ok(id) => Some(ElementId(id)),
+        Err(err) => {
+            log::error!(
+                "Dropping an event for an element with the malformed data-dioxus-id {:?}: {}",
+                id,
+                err
+            );
+            None
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Reading and controlling the dom nodes behind elements
+
+use dioxus_core::ElementId;
+use dioxus_html::geometry::{
+    euclid::{Point2D, Size2D},
+    ClientRect,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, FocusOptions, HtmlElement, ScrollIntoViewOptions, ScrollLogicalPosition};
+
+/// Find the dom element that was created for an element of the virtual dom
+fn get_element(id: ElementId) -> Option<Element> {
+    let node = dioxus_interpreter_js::get_node(id.0 as u32)?;
+
+    // The interpreter doesn't forget nodes when they are removed, so make sure the node is still on the page
+    if !node.is_connected() {
+        return None;
+    }
+
+    node.dyn_into().ok()
+}
+
+/// Measure the size and position of an element relative to the viewport, like `getBoundingClientRect`.
+///
+/// Returns `None` if the element isn't mounted anymore.
+///
+/// ```rust, ignore
+/// let rect = get_client_rect(id)?;
+/// // Open the menu right below the button
+/// menu_position.set((rect.min_x(), rect.max_y()));
+/// ```
+pub fn get_client_rect(id: ElementId) -> Option<ClientRect> {
+    let rect = get_element(id)?.get_bounding_client_rect();
+
+    Some(ClientRect::new(
+        Point2D::new(rect.x(), rect.y()),
+        Size2D::new(rect.width(), rect.height()),
+    ))
+}
+
+/// Whether [`scroll_into_view`] jumps straight to the element or animates the scroll
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollBehavior {
+    /// Scroll the way the page's `scroll-behavior` css says to
+    Auto,
+    /// Animate the scroll
+    Smooth,
+}
+
+/// Where [`scroll_into_view`] places the element in the visible area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollAlignment {
+    /// Line the element up with the top of the visible area
+    Start,
+    /// Center the element in the visible area
+    Center,
+    /// Line the element up with the bottom of the visible area
+    End,
+    /// Scroll as little as possible to show the element, and don't scroll at all if it's already visible
+    Nearest,
+}
+
+/// Scroll every scrollable ancestor of an element until the element is visible, like `scrollIntoView`.
+///
+/// Does nothing if the element isn't mounted anymore.
+///
+/// ```rust, ignore
+/// // Show the newest message at the bottom of the chat
+/// scroll_into_view(last_message, ScrollBehavior::Smooth, ScrollAlignment::End);
+/// ```
+pub fn scroll_into_view(id: ElementId, behavior: ScrollBehavior, block: ScrollAlignment) {
+    let element = match get_element(id) {
+        Some(element) => element,
+        None => return,
+    };
+
+    let mut options = ScrollIntoViewOptions::new();
+    options.behavior(match behavior {
+        ScrollBehavior::Auto => web_sys::ScrollBehavior::Auto,
+        ScrollBehavior::Smooth => web_sys::ScrollBehavior::Smooth,
+    });
+    options.block(match block {
+        ScrollAlignment::Start => ScrollLogicalPosition::Start,
+        ScrollAlignment::Center => ScrollLogicalPosition::Center,
+        ScrollAlignment::End => ScrollLogicalPosition::End,
+        ScrollAlignment::Nearest => ScrollLogicalPosition::Nearest,
+    });
+
+    element.scroll_into_view_with_scroll_into_view_options(&options);
+}
+
+/// Move the keyboard focus to an element, like `focus`.
+///
+/// Browsers scroll the element into view when it gets focus unless `prevent_scroll` is set. Does nothing if the element
+/// isn't mounted anymore or can't be focused.
+///
+/// ```rust, ignore
+/// // Let the user type into the dialog as soon as it opens
+/// focus(dialog_input, false);
+/// ```
+pub fn focus(id: ElementId, prevent_scroll: bool) {
+    if let Some(element) = get_html_element(id) {
+        let mut options = FocusOptions::new();
+        options.prevent_scroll(prevent_scroll);
+        _ = element.focus_with_options(&options);
+    }
+}
+
+/// Remove the keyboard focus from an element, like `blur`.
+///
+/// Does nothing if the element isn't mounted anymore or doesn't have focus.
+pub fn blur(id: ElementId) {
+    if let Some(element) = get_html_element(id) {
+        _ = element.blur();
+    }
+}
+
+fn get_html_element(id: ElementId) -> Option<HtmlElement> {
+    get_element(id)?.dyn_into().ok()
+}
@@ -0,0 +1,357 @@
+//! Reading files that were picked, dropped or pasted onto the page
+
+use std::{cell::RefCell, collections::HashMap};
+
+use dioxus_html::{DirEntry, FileEngine, FileEngineError};
+use futures_util::future::LocalBoxFuture;
+use js_sys::{Array, Function, Promise, Reflect, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, DataTransfer, File, FileReader, HtmlInputElement};
+
+/// A file engine backed by the files and entries of a `DataTransfer`, or the files of a file input
+pub(crate) struct WebFileEngine {
+    // The flat list of files, keyed by name
+    files: RefCell<HashMap<String, File>>,
+
+    // The top level `FileSystemEntry`s of the transfer, if the browser supports the entries API
+    entries: Option<Vec<JsValue>>,
+
+    // The accept attribute of the file input the files were picked with
+    accept: Option<String>,
+}
+
+impl WebFileEngine {
+    /// Collect the files out of a data transfer.
+    ///
+    /// This must be called while the event is being dispatched, since the browser protects the items of a data transfer
+    /// once the event is done.
+    #[cfg_attr(
+        not(any(feature = "drag-events", feature = "clipboard-events")),
+        allow(dead_code)
+    )]
+    pub fn new(data_transfer: &DataTransfer) -> Option<Self> {
+        let list = data_transfer.files()?;
+        let mut files = HashMap::new();
+        for idx in 0..list.length() {
+            if let Some(file) = list.item(idx) {
+                files.insert(file.name(), file);
+            }
+        }
+
+        let items = data_transfer.items();
+        let mut entries = Some(Vec::new());
+        for idx in 0..items.length() {
+            let item = match items.get(idx) {
+                Some(item) => item,
+                None => continue,
+            };
+
+            match (call_method(&item, "webkitGetAsEntry", &[]), &mut entries) {
+                // A null entry is a string item, the user didn't drop it as a file
+                (Some(entry), Some(entries)) if !entry.is_null() => entries.push(entry),
+                (Some(_), _) => {}
+                // The entries API isn't available, so fall back to the flat list of files
+                (None, _) => {
+                    entries = None;
+                    break;
+                }
+            }
+        }
+
+        // Some browsers only put pasted images in the items, not in the list of files
+        for idx in 0..items.length() {
+            let file = items
+                .get(idx)
+                .filter(|item| item.kind() == "file")
+                .and_then(|item| item.get_as_file().ok().flatten());
+            if let Some(file) = file {
+                files.entry(file.name()).or_insert(file);
+            }
+        }
+
+        // Pasted files usually don't have entries, so fall back to the flat list of files
+        let entries = entries.filter(|entries| !entries.is_empty());
+
+        if files.is_empty() && entries.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            files: RefCell::new(files),
+            entries,
+            accept: None,
+        })
+    }
+
+    /// Collect the files picked in a file input.
+    ///
+    /// Files picked with `webkitdirectory` are keyed by their path relative to the directory the user picked, so the
+    /// directory tree can be rebuilt from [`FileEngine::files`]. Other files are keyed by their name.
+    pub fn from_input(input: &HtmlInputElement) -> Option<Self> {
+        let list = input.files()?;
+        let mut files = HashMap::new();
+        for idx in 0..list.length() {
+            if let Some(file) = list.item(idx) {
+                files.insert(relative_path(&file), file);
+            }
+        }
+
+        if files.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            files: RefCell::new(files),
+            entries: None,
+            accept: input.get_attribute("accept"),
+        })
+    }
+
+    fn get_file(&self, path: &str) -> Option<File> {
+        self.files.borrow().get(path).cloned()
+    }
+
+    fn get_file_or_err(&self, path: &str) -> Result<File, FileEngineError> {
+        self.get_file(path)
+            .ok_or_else(|| FileEngineError::NotFound(path.to_string()))
+    }
+
+    /// Walk an entry, recursing into directories and remembering the files we find so they can be read later
+    fn walk(&self, entry: JsValue) -> LocalBoxFuture<'_, Option<DirEntry>> {
+        // Directories can be arbitrarily deep, so the recursion needs to be boxed
+        Box::pin(async move {
+            let name = Reflect::get(&entry, &"name".into()).ok()?.as_string()?;
+            let path = Reflect::get(&entry, &"fullPath".into())
+                .ok()
+                .and_then(|path| path.as_string())
+                .unwrap_or_else(|| name.clone());
+            let is_directory = Reflect::get(&entry, &"isDirectory".into())
+                .ok()?
+                .as_bool()
+                .unwrap_or(false);
+
+            if !is_directory {
+                let file = callback_to_future(&entry, "file", &[]).await?;
+                self.files
+                    .borrow_mut()
+                    .insert(path.clone(), file.dyn_into().ok()?);
+
+                return Some(DirEntry {
+                    name,
+                    path,
+                    children: None,
+                });
+            }
+
+            let reader = call_method(&entry, "createReader", &[])?;
+            let mut children = Vec::new();
+
+            // Readers return their entries in batches, and an empty batch means we've seen all of them
+            loop {
+                let batch: Array = callback_to_future(&reader, "readEntries", &[])
+                    .await?
+                    .dyn_into()
+                    .ok()?;
+
+                if batch.length() == 0 {
+                    break;
+                }
+
+                for child in batch.iter() {
+                    if let Some(child) = self.walk(child).await {
+                        children.push(child);
+                    }
+                }
+            }
+
+            Some(DirEntry {
+                name,
+                path,
+                children: Some(children),
+            })
+        })
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl FileEngine for WebFileEngine {
+    fn files(&self) -> Vec<String> {
+        self.files.borrow().keys().cloned().collect()
+    }
+
+    fn file_size(&self, file: &str) -> Option<u64> {
+        Some(self.get_file(file)?.size() as u64)
+    }
+
+    fn accept(&self) -> Option<String> {
+        self.accept.clone()
+    }
+
+    fn file_mime(&self, file: &str) -> Option<String> {
+        // The type is empty if the browser couldn't guess it from the extension
+        let mime = self.get_file(file)?.type_();
+        match mime.is_empty() {
+            true => None,
+            false => Some(mime),
+        }
+    }
+
+    async fn read_file(&self, file: &str) -> Result<Vec<u8>, FileEngineError> {
+        let blob = self.get_file_or_err(file)?;
+        read_blob(file, &blob).await
+    }
+
+    async fn read_file_to_string(&self, file: &str) -> Result<String, FileEngineError> {
+        let blob = self.get_file_or_err(file)?;
+        JsFuture::from(blob.text())
+            .await
+            .map_err(|err| read_error(file, err))?
+            .as_string()
+            .ok_or_else(|| FileEngineError::ReadFailed(file.to_string()))
+    }
+
+    async fn read_file_as_data_url(&self, file: &str) -> Result<String, FileEngineError> {
+        let blob = self.get_file_or_err(file)?;
+        let reader = FileReader::new().map_err(|err| read_error(file, err))?;
+
+        // The executor runs right away, so the handlers are set before the read starts
+        let loaded = Promise::new(&mut |resolve, reject| {
+            reader.set_onload(Some(&resolve));
+            reader.set_onerror(Some(&reject));
+        });
+        reader
+            .read_as_data_url(&blob)
+            .map_err(|err| read_error(file, err))?;
+
+        if let Err(event) = JsFuture::from(loaded).await {
+            // The error event doesn't say what went wrong, the reader does
+            let err = Reflect::get(&reader, &"error".into()).unwrap_or(event);
+            return Err(read_error(file, err));
+        }
+
+        reader
+            .result()
+            .ok()
+            .and_then(|url| url.as_string())
+            .ok_or_else(|| FileEngineError::ReadFailed(file.to_string()))
+    }
+
+    async fn read_file_range(
+        &self,
+        file: &str,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<u8>, FileEngineError> {
+        // Slicing a file is lazy, so only the bytes in the range are ever read
+        let slice = self
+            .get_file_or_err(file)?
+            .slice_with_f64_and_f64(start as f64, end as f64)
+            .map_err(|err| read_error(file, err))?;
+        read_blob(file, &slice).await
+    }
+
+    async fn entries(&self) -> Vec<DirEntry> {
+        let entries = match &self.entries {
+            Some(entries) => entries.clone(),
+            None => return tree_from_paths(self.files()),
+        };
+
+        let mut walked = Vec::new();
+        for entry in entries {
+            if let Some(entry) = self.walk(entry).await {
+                walked.push(entry);
+            }
+        }
+        walked
+    }
+}
+
+async fn read_blob(file: &str, blob: &Blob) -> Result<Vec<u8>, FileEngineError> {
+    let buffer = JsFuture::from(blob.array_buffer())
+        .await
+        .map_err(|err| read_error(file, err))?;
+    Ok(Uint8Array::new(&buffer).to_vec())
+}
+
+/// Turn the `DOMException` a read was rejected with into an error
+fn read_error(file: &str, err: JsValue) -> FileEngineError {
+    let name = Reflect::get(&err, &"name".into())
+        .ok()
+        .and_then(|name| name.as_string())
+        .unwrap_or_default();
+
+    match name.as_str() {
+        // The file was moved or deleted after it was picked
+        "NotFoundError" => FileEngineError::NotFound(file.to_string()),
+        "NotAllowedError" | "SecurityError" => FileEngineError::PermissionDenied(file.to_string()),
+        _ => FileEngineError::ReadFailed(file.to_string()),
+    }
+}
+
+/// The path of a file relative to the directory picked with `webkitdirectory`, or its name for any other file
+fn relative_path(file: &File) -> String {
+    Reflect::get(file, &"webkitRelativePath".into())
+        .ok()
+        .and_then(|path| path.as_string())
+        .filter(|path| !path.is_empty())
+        .unwrap_or_else(|| file.name())
+}
+
+/// Rebuild a tree of directories out of a list of `/` separated paths
+fn tree_from_paths(mut paths: Vec<String>) -> Vec<DirEntry> {
+    paths.sort();
+
+    let mut root = Vec::new();
+    for path in paths {
+        let segments: Vec<&str> = path.split('/').collect();
+        let mut level: &mut Vec<DirEntry> = &mut root;
+
+        for (idx, segment) in segments.iter().enumerate() {
+            let is_file = idx == segments.len() - 1;
+            let existing = level
+                .iter()
+                .position(|entry| entry.name == *segment && entry.is_dir() != is_file);
+
+            let pos = match existing {
+                Some(pos) => pos,
+                None => {
+                    level.push(DirEntry {
+                        name: segment.to_string(),
+                        path: segments[..=idx].join("/"),
+                        children: (!is_file).then(Vec::new),
+                    });
+                    level.len() - 1
+                }
+            };
+
+            match &mut level[pos].children {
+                Some(children) => level = children,
+                None => break,
+            }
+        }
+    }
+
+    root
+}
+
+/// Call a method on a JS object if it exists
+fn call_method(this: &JsValue, name: &str, args: &[JsValue]) -> Option<JsValue> {
+    let method: Function = Reflect::get(this, &name.into()).ok()?.dyn_into().ok()?;
+    let args: Array = args.iter().collect();
+    method.apply(this, &args).ok()
+}
+
+/// Call a method that takes a success and an error callback, and wait for the result
+async fn callback_to_future(this: &JsValue, name: &str, args: &[JsValue]) -> Option<JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let mut args = args.to_vec();
+        args.push(resolve.into());
+        args.push(reject.clone().into());
+        if call_method(this, name, &args).is_none() {
+            _ = reject.call0(&JsValue::NULL);
+        }
+    });
+
+    JsFuture::from(promise).await.ok()
+}
@@ -0,0 +1,125 @@
+//! Listening for events on the document or window, outside of any element
+
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus_core::ScopeState;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Element, Event, EventTarget};
+
+use crate::dom::virtual_event_from_websys_event;
+
+/// Where an event is listened for when it isn't listened for on an element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobalTarget {
+    /// The document, which sees every event that bubbles, wherever the focus is
+    Document,
+    /// The window, which is where events like `resize` and `popstate` are dispatched
+    Window,
+}
+
+/// Listen for an event on the document or window for as long as the component is mounted.
+///
+/// Element listeners only see events that happen inside the element, so this is how to handle an event wherever it
+/// happens, like a keyboard shortcut that works no matter what is focused. The data of the event is decoded like it
+/// would be for an element listener, so `T` is the data type of the event, like [`dioxus_html::KeyboardData`] for
+/// `keydown`. Events whose data doesn't have that type are ignored.
+///
+/// The handler is replaced on every render, so it always sees the latest values it captures. The listener is removed
+/// when the component is dropped.
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let palette_open = use_state(cx, || false);
+///
+///     use_global_listener(cx, GlobalTarget::Document, "keydown", {
+///         let palette_open = palette_open.clone();
+///         move |evt: Rc<KeyboardData>| {
+///             if evt.modifiers().ctrl() && evt.key() == Key::Character("k".into()) {
+///                 palette_open.set(true);
+///             }
+///         }
+///     });
+///
+///     cx.render(rsx! { CommandPalette { open: **palette_open } })
+/// }
+/// ```
+pub fn use_global_listener<T: 'static>(
+    cx: &ScopeState,
+    target: GlobalTarget,
+    name: &'static str,
+    handler: impl FnMut(Rc<T>) + 'static,
+) {
+    let listener = cx.use_hook(|| GlobalListener::new(target, name));
+    *listener.handler.borrow_mut() = Box::new(handler);
+}
+
+struct GlobalListener<T> {
+    name: &'static str,
+    target: Option<EventTarget>,
+    handler: Rc<RefCell<Box<dyn FnMut(Rc<T>)>>>,
+    callback: Closure<dyn FnMut(&Event)>,
+}
+
+impl<T: 'static> GlobalListener<T> {
+    fn new(target: GlobalTarget, name: &'static str) -> Self {
+        let handler: Rc<RefCell<Box<dyn FnMut(Rc<T>)>>> = Rc::new(RefCell::new(Box::new(|_| {})));
+
+        let callback: Closure<dyn FnMut(&Event)> = Closure::wrap(Box::new({
+            let handler = handler.clone();
+            move |event: &Event| {
+                let element = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .or_else(|| web_sys::window()?.document()?.document_element());
+                let element = match element {
+                    Some(element) => element,
+                    None => return,
+                };
+
+                let data = match virtual_event_from_websys_event(name, event, element).downcast() {
+                    Ok(data) => data,
+                    Err(_) => return,
+                };
+
+                // The handler could dispatch the same event again, which we can't deliver while it's running
+                if let Ok(mut handler) = handler.try_borrow_mut() {
+                    handler(data);
+                }
+            }
+        }));
+
+        let window = web_sys::window();
+        let target: Option<EventTarget> = match target {
+            GlobalTarget::Window => window.map(|window| window.unchecked_into()),
+            GlobalTarget::Document => window
+                .and_then(|window| window.document())
+                .map(|document| document.unchecked_into()),
+        };
+
+        match &target {
+            Some(target) => {
+                _ = target
+                    .add_event_listener_with_callback(name, callback.as_ref().unchecked_ref());
+            }
+            None => log::warn!("Couldn't find the target of the global {} listener", name),
+        }
+
+        Self {
+            name,
+            target,
+            handler,
+            callback,
+        }
+    }
+}
+
+impl<T> Drop for GlobalListener<T> {
+    fn drop(&mut self) {
+        if let Some(target) = &self.target {
+            _ = target.remove_event_listener_with_callback(
+                self.name,
+                self.callback.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
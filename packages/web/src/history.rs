@@ -0,0 +1,70 @@
+//! Navigating the session history for client-side routing
+
+use serde::Serialize;
+
+/// Push a new entry onto the session history with the given url and state.
+///
+/// The state is serialized to JSON and is handed back in [`dioxus_html::HistoryData::state`] when the user navigates
+/// back to this entry. Pushing an entry doesn't fire `popstate`, so update your own route state after calling this.
+///
+/// Returns `false` if the state couldn't be serialized or the browser refused the navigation.
+///
+/// ```rust, ignore
+/// button {
+///     onclick: move |_| {
+///         push_state("/about", &());
+///         route.set("/about");
+///     },
+///     "About"
+/// }
+/// ```
+pub fn push_state(url: &str, state: &impl Serialize) -> bool {
+    update_history(url, state, |history, state, url| {
+        history.push_state_with_url(state, "", Some(url))
+    })
+}
+
+/// Replace the current entry of the session history with the given url and state.
+///
+/// This works like [`push_state`], but the user can't navigate back to the entry that was replaced.
+pub fn replace_state(url: &str, state: &impl Serialize) -> bool {
+    update_history(url, state, |history, state, url| {
+        history.replace_state_with_url(state, "", Some(url))
+    })
+}
+
+/// Set the hash of the url, like `#/about`, for routing that only changes the hash.
+///
+/// The leading `#` is optional. If the hash changed, this adds an entry to the session history and fires
+/// `hashchange`, so a router listening for `onhashchange` picks up the new route on its own.
+///
+/// Returns `false` if the browser refused the navigation.
+pub fn set_hash(hash: &str) -> bool {
+    match web_sys::window() {
+        Some(window) => window.location().set_hash(hash).is_ok(),
+        None => false,
+    }
+}
+
+fn update_history(
+    url: &str,
+    state: &impl Serialize,
+    update: impl FnOnce(
+        &web_sys::History,
+        &wasm_bindgen::JsValue,
+        &str,
+    ) -> Result<(), wasm_bindgen::JsValue>,
+) -> bool {
+    let state = match serde_json::to_string(state)
+        .ok()
+        .and_then(|json| js_sys::JSON::parse(&json).ok())
+    {
+        Some(state) => state,
+        None => return false,
+    };
+
+    match web_sys::window().and_then(|window| window.history().ok()) {
+        Some(history) => update(&history, &state, url).is_ok(),
+        None => false,
+    }
+}
@@ -17,6 +17,21 @@
 //!
 //! To purview the examples, check of the root Dioxus crate - the examples in this crate are mostly meant to provide
 //! validation of websys-specific features and not the general use of Dioxus.
+//!
+//! ## Event features
+//! ------------
+//! Decoding for some groups of events can be compiled out to shrink the wasm binary. They are all enabled by default
+//! through the `all-events` feature:
+//! - `media-events`: `onplay`, `ontimeupdate` and the other media events
+//! - `drag-events`: `ondrag`, `ondrop` and the other drag events
+//! - `pointer-events`: `onpointermove` and the other pointer events
+//! - `clipboard-events`: `oncopy`, `oncut` and `onpaste`
+//!
+//! `onpointerrawupdate` can fire many times per frame, so it is only decoded with the `pointer-raw-update` feature,
+//! which isn't enabled by default.
+//!
+//! Listeners for an event whose decoding is compiled out still compile, but they are never called. A warning naming
+//! the missing feature is logged the first time the event fires.
 
 // ## RequestAnimationFrame and RequestIdleCallback
 // ------------------------------------------------
@@ -54,17 +69,41 @@
 //     - Do DOM work in the next requestAnimationFrame callback
 
 pub use crate::cfg::Config;
+pub use crate::clipboard::read_clipboard_text;
+pub use crate::element::{
+    blur, focus, get_client_rect, scroll_into_view, ScrollAlignment, ScrollBehavior,
+};
+pub use crate::global_listener::{use_global_listener, GlobalTarget};
+pub use crate::history::{push_state, replace_state, set_hash};
+pub use crate::media_query::use_media_query;
+pub use crate::persistent::{use_persistent, use_session_persistent, UsePersistent};
+pub use crate::portal::{use_portal, Portal};
+pub use crate::reorder::{use_drag_reorder, UseDragReorder};
+pub use crate::script::{use_script, Script};
 pub use crate::util::{use_eval, EvalResult};
+pub use crate::window_size::use_window_size;
 use dioxus_core::{Element, Scope, VirtualDom};
 use futures_util::{pin_mut, FutureExt, StreamExt};
 
 mod cache;
 mod cfg;
+mod clipboard;
 mod dom;
+mod element;
+mod file_engine;
+mod global_listener;
+mod history;
 mod hot_reload;
+mod media_query;
+mod observer;
+mod persistent;
+mod portal;
 #[cfg(feature = "hydrate")]
 mod rehydrate;
+mod reorder;
+mod script;
 mod util;
+mod window_size;
 
 // Currently disabled since it actually slows down immediate rendering
 // todo: only schedule non-immediate renders through ric/raf
@@ -188,8 +227,22 @@ pub async fn run_with_props<T: 'static>(root: fn(Scope<T>) -> Element, root_prop
     #[cfg(not(feature = "hydrate"))]
     let should_hydrate = false;
 
+    let time_slice = cfg.time_slice;
+    let performance = web_sys::window().and_then(|window| window.performance());
+
     let mut websys_dom = dom::WebsysDom::new(cfg, tx);
 
+    // There's nothing to adopt if the page wasn't pre-rendered, so build the dom from scratch instead
+    #[cfg(feature = "hydrate")]
+    let should_hydrate = if should_hydrate && !websys_dom.root.has_child_nodes() {
+        log::warn!(
+            "Hydration is enabled, but the root element is empty. Rendering the app from scratch"
+        );
+        false
+    } else {
+        should_hydrate
+    };
+
     log::info!("rebuilding app");
 
     if should_hydrate {
@@ -265,12 +318,27 @@ pub async fn run_with_props<T: 'static>(root: fn(Scope<T>) -> Element, root_prop
         // let deadline = work_loop.wait_for_idle_time().await;
 
         // run the virtualdom work phase until the frame deadline is reached
-        let edits = dom.render_immediate();
+        let mut out_of_time = false;
+        let edits = match (time_slice, &performance) {
+            (Some(budget), Some(performance)) => {
+                let start = performance.now();
+                dom.render_until(|| {
+                    out_of_time = performance.now() - start > budget;
+                    out_of_time
+                })
+            }
+            _ => dom.render_immediate(),
+        };
 
         // wait for the animation frame to fire so we can apply our changes
         // work_loop.wait_for_raf().await;
 
         websys_dom.load_templates(&edits.templates);
         websys_dom.apply_edits(edits.edits);
+
+        // There's more to render, but let the browser handle input and paint what we have first
+        if out_of_time {
+            gloo_timers::future::TimeoutFuture::new(0).await;
+        }
     }
 }
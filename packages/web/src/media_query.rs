@@ -0,0 +1,151 @@
+//! Watching css media queries like `(prefers-color-scheme: dark)`
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    sync::Arc,
+};
+
+use dioxus_core::ScopeState;
+use rustc_hash::FxHashMap;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::MediaQueryList;
+
+/// Check if a css media query matches, re-rendering the component whenever that changes.
+///
+/// The value returned on the first render is already the current state of the query, so components can pick a
+/// layout straight away. Every component watching the same query shares one listener, which is removed once the last
+/// of them is dropped.
+///
+/// Queries the browser doesn't understand never match.
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let dark = use_media_query(cx, "(prefers-color-scheme: dark)");
+///     let small = use_media_query(cx, "(max-width: 600px)");
+///
+///     cx.render(rsx! {
+///         div { class: if dark { "dark" } else { "light" },
+///             if small { rsx!(MobileMenu {}) } else { rsx!(Sidebar {}) }
+///         }
+///     })
+/// }
+/// ```
+pub fn use_media_query(cx: &ScopeState, query: &str) -> bool {
+    let subscription = cx.use_hook(|| Subscription::new(cx, query));
+
+    if subscription.query != query {
+        *subscription = Subscription::new(cx, query);
+    }
+
+    subscription.matches()
+}
+
+thread_local! {
+    // Every query a component is watching, so each distinct query only has one listener
+    static QUERIES: RefCell<FxHashMap<String, Rc<SharedQuery>>> = RefCell::new(FxHashMap::default());
+}
+
+type Subscribers = Rc<RefCell<Vec<(usize, Arc<dyn Fn() + Send + Sync>)>>>;
+
+struct SharedQuery {
+    list: MediaQueryList,
+    subscribers: Subscribers,
+    next_subscriber: Cell<usize>,
+    on_change: Closure<dyn FnMut()>,
+}
+
+impl SharedQuery {
+    fn new(query: &str) -> Option<Self> {
+        let list = web_sys::window()?.match_media(query).ok()??;
+        let subscribers: Subscribers = Default::default();
+
+        let on_change = Closure::wrap(Box::new({
+            let subscribers = subscribers.clone();
+            move || {
+                for (_, update) in subscribers.borrow().iter() {
+                    update();
+                }
+            }
+        }) as Box<dyn FnMut()>);
+
+        _ = list.add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref());
+
+        Some(Self {
+            list,
+            subscribers,
+            next_subscriber: Default::default(),
+            on_change,
+        })
+    }
+
+    fn subscribe(&self, update: Arc<dyn Fn() + Send + Sync>) -> usize {
+        let id = self.next_subscriber.get();
+        self.next_subscriber.set(id + 1);
+        self.subscribers.borrow_mut().push((id, update));
+        id
+    }
+}
+
+impl Drop for SharedQuery {
+    fn drop(&mut self) {
+        _ = self
+            .list
+            .remove_event_listener_with_callback("change", self.on_change.as_ref().unchecked_ref());
+    }
+}
+
+struct Subscription {
+    query: String,
+    // The shared query and our id in its subscribers, if the browser understood the query
+    shared: Option<(Rc<SharedQuery>, usize)>,
+}
+
+impl Subscription {
+    fn new(cx: &ScopeState, query: &str) -> Self {
+        let shared = QUERIES.with(|queries| {
+            let mut queries = queries.borrow_mut();
+            let shared = match queries.get(query) {
+                Some(shared) => shared.clone(),
+                None => {
+                    let shared = Rc::new(SharedQuery::new(query)?);
+                    queries.insert(query.to_string(), shared.clone());
+                    shared
+                }
+            };
+            let id = shared.subscribe(cx.schedule_update());
+            Some((shared, id))
+        });
+
+        if shared.is_none() {
+            log::warn!("Couldn't watch the media query {:?}", query);
+        }
+
+        Self {
+            query: query.to_string(),
+            shared,
+        }
+    }
+
+    fn matches(&self) -> bool {
+        match &self.shared {
+            Some((shared, _)) => shared.list.matches(),
+            None => false,
+        }
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some((shared, id)) = self.shared.take() {
+            let mut subscribers = shared.subscribers.borrow_mut();
+            subscribers.retain(|(subscriber, _)| *subscriber != id);
+
+            // Nobody is watching the query anymore, so dropping it removes the listener
+            if subscribers.is_empty() {
+                drop(subscribers);
+                QUERIES.with(|queries| queries.borrow_mut().remove(&self.query));
+            }
+        }
+    }
+}
@@ -0,0 +1,292 @@
+//! Element events that come from an observer instead of the dom, like `onvisible` and `onresize`
+
+use crate::dom::{send_event, UiEvent};
+use dioxus_core::ElementId;
+use dioxus_html::{ResizeData, VisibleData};
+use futures_channel::mpsc;
+use std::{any::Any, cell::RefCell, rc::Rc};
+use wasm_bindgen::{closure::Closure, prelude::*, JsCast};
+use web_sys::{DomRectReadOnly, Element, IntersectionObserver, IntersectionObserverEntry};
+
+/// An observer that watches elements and reports a list of entries when they change
+pub(crate) trait Observer: Sized {
+    /// The name of the event the entries are delivered as
+    const EVENT: &'static str;
+
+    fn create(callback: &js_sys::Function) -> Option<Self>;
+
+    fn observe(&self, element: &Element);
+
+    fn unobserve(&self, element: &Element);
+
+    fn disconnect(&self);
+
+    /// The element an entry is about, and the data of the event we send for it
+    fn read_entry(entry: JsValue) -> (Element, Rc<dyn Any>);
+
+    /// The data of an event to send as soon as an element starts being watched, for when the observer itself
+    /// wouldn't report it
+    fn initial_data(_element: &Element) -> Option<Rc<dyn Any>> {
+        None
+    }
+}
+
+impl Observer for IntersectionObserver {
+    const EVENT: &'static str = "visible";
+
+    fn create(callback: &js_sys::Function) -> Option<Self> {
+        IntersectionObserver::new(callback).ok()
+    }
+
+    fn observe(&self, element: &Element) {
+        self.observe(element)
+    }
+
+    fn unobserve(&self, element: &Element) {
+        self.unobserve(element)
+    }
+
+    fn disconnect(&self) {
+        self.disconnect()
+    }
+
+    fn read_entry(entry: JsValue) -> (Element, Rc<dyn Any>) {
+        let entry: IntersectionObserverEntry = entry.unchecked_into();
+        let data = VisibleData {
+            is_intersecting: entry.is_intersecting(),
+            intersection_ratio: entry.intersection_ratio(),
+        };
+        (entry.target(), Rc::new(data))
+    }
+}
+
+// web-sys only has bindings for ResizeObserver when it's built with the unstable apis enabled
+#[wasm_bindgen]
+extern "C" {
+    pub(crate) type ResizeObserver;
+
+    #[wasm_bindgen(constructor, catch)]
+    fn new(callback: &js_sys::Function) -> Result<ResizeObserver, JsValue>;
+
+    #[wasm_bindgen(method)]
+    fn observe(this: &ResizeObserver, target: &Element);
+
+    #[wasm_bindgen(method)]
+    fn unobserve(this: &ResizeObserver, target: &Element);
+
+    #[wasm_bindgen(method)]
+    fn disconnect(this: &ResizeObserver);
+
+    type ResizeObserverEntry;
+
+    #[wasm_bindgen(method, getter)]
+    fn target(this: &ResizeObserverEntry) -> Element;
+
+    #[wasm_bindgen(method, getter, js_name = contentRect)]
+    fn content_rect(this: &ResizeObserverEntry) -> DomRectReadOnly;
+}
+
+impl Observer for ResizeObserver {
+    const EVENT: &'static str = "resize";
+
+    fn create(callback: &js_sys::Function) -> Option<Self> {
+        ResizeObserver::new(callback).ok()
+    }
+
+    fn observe(&self, element: &Element) {
+        self.observe(element)
+    }
+
+    fn unobserve(&self, element: &Element) {
+        self.unobserve(element)
+    }
+
+    fn disconnect(&self) {
+        self.disconnect()
+    }
+
+    fn read_entry(entry: JsValue) -> (Element, Rc<dyn Any>) {
+        let entry: ResizeObserverEntry = entry.unchecked_into();
+        let rect = entry.content_rect();
+        let data = ResizeData {
+            x: rect.x(),
+            y: rect.y(),
+            width: rect.width(),
+            height: rect.height(),
+        };
+        (entry.target(), Rc::new(data))
+    }
+
+    fn initial_data(element: &Element) -> Option<Rc<dyn Any>> {
+        // Browsers only report the first size of an element if it isn't empty, but listeners should always get the
+        // size the element starts out with
+        let rect = element.get_bounding_client_rect();
+        if rect.width() == 0.0 && rect.height() == 0.0 {
+            Some(Rc::new(ResizeData::default()))
+        } else {
+            None
+        }
+    }
+}
+
+/// The elements an observer is watching.
+///
+/// Observers report the elements that changed, so this maps them back to the ids their events are delivered to.
+#[derive(Clone, Default)]
+struct Observed {
+    elements: Rc<RefCell<Vec<(ElementId, Element)>>>,
+}
+
+impl Observed {
+    fn find(&self, target: &Element) -> Option<ElementId> {
+        self.elements
+            .borrow()
+            .iter()
+            .find(|(_, element)| element == target)
+            .map(|(id, _)| *id)
+    }
+
+    /// Start watching the elements that were just created, and stop watching elements that were removed.
+    ///
+    /// Core doesn't tell us about listeners whose elements were removed along with their parent, so everything that
+    /// isn't on the page anymore is dropped here to avoid leaking it.
+    fn flush(
+        &self,
+        queued: &mut Vec<ElementId>,
+        mut observe: impl FnMut(ElementId, &Element),
+        unobserve: impl Fn(&Element),
+    ) {
+        let mut elements = self.elements.borrow_mut();
+
+        elements.retain(|(_, element)| {
+            let connected = element.is_connected();
+            if !connected {
+                unobserve(element);
+            }
+            connected
+        });
+
+        for id in queued.drain(..) {
+            let element = dioxus_interpreter_js::get_node(id.0 as u32)
+                .and_then(|node| node.dyn_into::<Element>().ok());
+            if let Some(element) = element {
+                observe(id, &element);
+                elements.push((id, element));
+            }
+        }
+    }
+
+    fn remove(&self, id: ElementId, unobserve: impl Fn(&Element)) {
+        self.elements.borrow_mut().retain(|(observed, element)| {
+            if *observed == id {
+                unobserve(element);
+            }
+            *observed != id
+        });
+    }
+}
+
+/// A single `IntersectionObserver` shared by every element with an `onvisible` listener
+pub(crate) type VisibilityObserver = ElementObserver<IntersectionObserver>;
+
+/// A single `ResizeObserver` shared by every element with an `onresize` listener
+pub(crate) type SizeObserver = ElementObserver<ResizeObserver>;
+
+/// A single observer shared by every element that listens to the event it reports
+pub(crate) struct ElementObserver<O: Observer> {
+    event_channel: mpsc::UnboundedSender<UiEvent>,
+    // Created the first time an element listens to the event
+    observer: Option<(O, Closure<dyn FnMut(js_sys::Array)>)>,
+    observed: Observed,
+    // Elements whose listeners were created by the edits that are being applied
+    queued: Vec<ElementId>,
+}
+
+impl<O: Observer> ElementObserver<O> {
+    pub(crate) fn new(event_channel: mpsc::UnboundedSender<UiEvent>) -> Self {
+        Self {
+            event_channel,
+            observer: None,
+            observed: Observed::default(),
+            queued: Vec::new(),
+        }
+    }
+
+    /// Watch an element once the edits that create it have been flushed
+    pub(crate) fn queue(&mut self, id: ElementId) {
+        self.queued.push(id);
+    }
+
+    /// Forget the queued elements, for when hydration fails and the dom is rebuilt with new elements
+    #[cfg(feature = "hydrate")]
+    pub(crate) fn clear_queue(&mut self) {
+        self.queued.clear();
+    }
+
+    pub(crate) fn remove(&mut self, id: ElementId) {
+        if let Some((observer, _)) = &self.observer {
+            self.observed
+                .remove(id, |element| observer.unobserve(element));
+        }
+    }
+
+    pub(crate) fn flush(&mut self) {
+        if self.observer.is_none() {
+            if self.queued.is_empty() {
+                return;
+            }
+
+            self.observer = create_observer(&self.observed, &self.event_channel);
+            if self.observer.is_none() {
+                log::warn!(
+                    "Couldn't create an observer, so on{} won't be called",
+                    O::EVENT
+                );
+                self.queued.clear();
+                return;
+            }
+        }
+
+        if let Some((observer, _)) = &self.observer {
+            let event_channel = &self.event_channel;
+            self.observed.flush(
+                &mut self.queued,
+                |id, element| {
+                    observer.observe(element);
+                    if let Some(data) = O::initial_data(element) {
+                        send_event(event_channel, O::EVENT, id, data);
+                    }
+                },
+                |element| observer.unobserve(element),
+            );
+        }
+    }
+}
+
+fn create_observer<O: Observer>(
+    observed: &Observed,
+    event_channel: &mpsc::UnboundedSender<UiEvent>,
+) -> Option<(O, Closure<dyn FnMut(js_sys::Array)>)> {
+    let observed = observed.clone();
+    let event_channel = event_channel.clone();
+    let callback: Closure<dyn FnMut(js_sys::Array)> =
+        Closure::wrap(Box::new(move |entries: js_sys::Array| {
+            for entry in entries.iter() {
+                let (target, data) = O::read_entry(entry);
+                if let Some(id) = observed.find(&target) {
+                    send_event(&event_channel, O::EVENT, id, data);
+                }
+            }
+        }));
+
+    let observer = O::create(callback.as_ref().unchecked_ref())?;
+    Some((observer, callback))
+}
+
+impl<O: Observer> Drop for ElementObserver<O> {
+    fn drop(&mut self) {
+        if let Some((observer, _)) = &self.observer {
+            observer.disconnect();
+        }
+    }
+}
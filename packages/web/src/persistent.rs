@@ -0,0 +1,137 @@
+//! State that survives page reloads by persisting itself to web storage
+
+use std::{
+    cell::{Ref, RefCell},
+    fmt::Debug,
+    rc::Rc,
+    sync::Arc,
+};
+
+use dioxus_core::ScopeState;
+use serde::{de::DeserializeOwned, Serialize};
+use web_sys::Storage;
+
+/// Store a value that is persisted in `localStorage` under the given key.
+///
+/// On the first render the value is read from storage, falling back to `init` if there is no stored value or the stored
+/// value can't be deserialized. Every time the value is changed through the returned handle, it is written back to
+/// storage as JSON.
+///
+/// If storage is disabled or full, the value is still kept in memory, it just won't survive a reload.
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let theme = use_persistent(cx, "theme", || "light".to_string());
+///
+///     cx.render(rsx! {
+///         button {
+///             onclick: move |_| theme.set("dark".to_string()),
+///             "Current theme: {theme.get()}"
+///         }
+///     })
+/// }
+/// ```
+pub fn use_persistent<T: Serialize + DeserializeOwned + 'static>(
+    cx: &ScopeState,
+    key: impl ToString,
+    init: impl FnOnce() -> T,
+) -> &UsePersistent<T> {
+    cx.use_hook(|| {
+        let storage = web_sys::window().and_then(|window| window.local_storage().ok().flatten());
+        UsePersistent::new(cx, key.to_string(), storage, init)
+    })
+}
+
+/// Store a value that is persisted in `sessionStorage` under the given key.
+///
+/// This works the same way as [`use_persistent`], but the value only lives as long as the browser tab does.
+pub fn use_session_persistent<T: Serialize + DeserializeOwned + 'static>(
+    cx: &ScopeState,
+    key: impl ToString,
+    init: impl FnOnce() -> T,
+) -> &UsePersistent<T> {
+    cx.use_hook(|| {
+        let storage = web_sys::window().and_then(|window| window.session_storage().ok().flatten());
+        UsePersistent::new(cx, key.to_string(), storage, init)
+    })
+}
+
+/// A handle to a value that is persisted to web storage.
+///
+/// Created with [`use_persistent`] or [`use_session_persistent`].
+pub struct UsePersistent<T> {
+    key: String,
+    storage: Option<Storage>,
+    value: Rc<RefCell<T>>,
+    update: Arc<dyn Fn()>,
+}
+
+impl<T: Serialize + DeserializeOwned> UsePersistent<T> {
+    fn new(
+        cx: &ScopeState,
+        key: String,
+        storage: Option<Storage>,
+        init: impl FnOnce() -> T,
+    ) -> Self {
+        let stored = storage
+            .as_ref()
+            .and_then(|storage| storage.get_item(&key).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok());
+
+        Self {
+            key,
+            storage,
+            value: Rc::new(RefCell::new(stored.unwrap_or_else(init))),
+            update: cx.schedule_update(),
+        }
+    }
+
+    /// Read the current value.
+    pub fn get(&self) -> Ref<'_, T> {
+        self.value.borrow()
+    }
+
+    /// Replace the value, persist it, and re-render the component.
+    pub fn set(&self, value: T) {
+        *self.value.borrow_mut() = value;
+        self.persist();
+    }
+
+    /// Modify the value in place, persist it, and re-render the component.
+    pub fn modify(&self, f: impl FnOnce(&mut T)) {
+        f(&mut self.value.borrow_mut());
+        self.persist();
+    }
+
+    /// Remove the value from storage without changing the value in memory.
+    pub fn clear(&self) {
+        if let Some(storage) = &self.storage {
+            _ = storage.remove_item(&self.key);
+        }
+    }
+
+    fn persist(&self) {
+        if let Some(storage) = &self.storage {
+            match serde_json::to_string(&*self.value.borrow()) {
+                // Writing fails if storage is full or disabled, in which case the value only lives in memory
+                Ok(json) => {
+                    if let Err(err) = storage.set_item(&self.key, &json) {
+                        log::warn!("Failed to persist {:?}: {:?}", self.key, err);
+                    }
+                }
+                Err(err) => log::warn!("Failed to serialize {:?}: {}", self.key, err),
+            }
+        }
+
+        (self.update)();
+    }
+}
+
+impl<T: Debug> Debug for UsePersistent<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UsePersistent")
+            .field("key", &self.key)
+            .field("value", &self.value.borrow())
+            .finish()
+    }
+}
@@ -0,0 +1,134 @@
+//! Rendering part of the tree somewhere else on the page
+
+use dioxus_core::{ElementId, ScopeState};
+use std::cell::RefCell;
+use web_sys::{Element, Node};
+
+/// Move elements to the end of another element on the page, like the body, while they stay where they were in the
+/// component tree.
+///
+/// Modals, tooltips and dropdowns often need to escape the `overflow: hidden` or stacking context of the elements
+/// they're rendered in. Elements moved into a portal keep the context of the components above them, and their events
+/// still bubble up through those components.
+///
+/// Adopt an element from its `onmounted` listener to move it. Core keeps placing the siblings of the element next to
+/// where it was rendered, so keep it inside an element that stays behind. An adopted element is removed along with
+/// the elements it was rendered in, and every element the portal adopted is removed when the component is dropped.
+///
+/// ```rust, ignore
+/// fn Modal(cx: Scope) -> Element {
+///     let portal = use_portal(cx, "body");
+///
+///     cx.render(rsx! {
+///         div { hidden: true,
+///             div { class: "modal", onmounted: move |evt| portal.adopt(evt.id),
+///                 "This is rendered at the end of the body"
+///             }
+///         }
+///     })
+/// }
+/// ```
+pub fn use_portal<'a>(cx: &'a ScopeState, target: &str) -> &'a Portal {
+    cx.use_hook(|| Portal::new(target))
+}
+
+/// A container at the end of another element on the page, created with [`use_portal`]
+pub struct Portal {
+    container: Option<Element>,
+}
+
+impl Portal {
+    fn new(target: &str) -> Self {
+        let container = create_container(target);
+
+        if container.is_none() {
+            log::warn!("Couldn't find the target {:?} of the portal", target);
+        }
+
+        Self { container }
+    }
+
+    /// Move an element into the portal
+    pub fn adopt(&self, id: ElementId) {
+        let node = dioxus_interpreter_js::get_node(id.0 as u32);
+        if let (Some(container), Some(node)) = (&self.container, node) {
+            // Leave a marker where the element was rendered, so we can tell when core removes the elements around it
+            if let Some(anchor) = place_anchor(&node) {
+                ADOPTED.with(|adopted| {
+                    adopted.borrow_mut().push(Adopted {
+                        anchor,
+                        node: node.clone(),
+                    })
+                });
+            }
+            _ = container.append_child(&node);
+        }
+    }
+}
+
+thread_local! {
+    // Every element adopted by a portal that is still on the page
+    static ADOPTED: RefCell<Vec<Adopted>> = RefCell::new(Vec::new());
+}
+
+struct Adopted {
+    anchor: Node,
+    node: Node,
+}
+
+fn place_anchor(node: &Node) -> Option<Node> {
+    let parent = node.parent_node()?;
+    let anchor: Node = web_sys::window()?
+        .document()?
+        .create_comment("portal")
+        .into();
+    parent.insert_before(&anchor, Some(node)).ok()?;
+    Some(anchor)
+}
+
+/// Remove the adopted elements whose surroundings were removed by the last edits.
+///
+/// Core only removes the outermost element of a subtree, which takes the marker of an adopted element with it but not
+/// the element itself. Once core frees their ids, an element left behind in the portal would never be removed.
+pub(crate) fn remove_orphaned_nodes() {
+    ADOPTED.with(|adopted| {
+        adopted.borrow_mut().retain(|adopted| {
+            if !adopted.node.is_connected() {
+                remove_node(&adopted.anchor);
+                false
+            } else if !adopted.anchor.is_connected() {
+                remove_node(&adopted.node);
+                false
+            } else {
+                true
+            }
+        })
+    });
+}
+
+fn remove_node(node: &Node) {
+    if let Some(parent) = node.parent_node() {
+        _ = parent.remove_child(node);
+    }
+}
+
+fn create_container(target: &str) -> Option<Element> {
+    let document = web_sys::window()?.document()?;
+    let target = document.query_selector(target).ok()??;
+    let container = document.create_element("div").ok()?;
+    target.append_child(&container).ok()?;
+
+    // Bubbling events are delegated from the root, which isn't an ancestor of the container
+    dioxus_interpreter_js::add_listener_root(container.clone().into());
+
+    Some(container)
+}
+
+impl Drop for Portal {
+    fn drop(&mut self) {
+        if let Some(container) = self.container.take() {
+            dioxus_interpreter_js::remove_listener_root(container.clone().into());
+            container.remove();
+        }
+    }
+}
@@ -3,7 +3,6 @@ use dioxus_core::{
     AttributeValue, DynamicNode, ElementId, ScopeState, TemplateNode, VNode, VPlaceholder, VText,
     VirtualDom,
 };
-use dioxus_html::event_bubbles;
 use wasm_bindgen::JsCast;
 use web_sys::{Comment, Node};
 
@@ -45,15 +44,24 @@ impl WebsysDom {
         let mut last_node_was_static_text = false;
 
         // Recursively rehydrate the dom from the VirtualDom
-        self.rehydrate_scope(
+        let rehydrated = self.rehydrate_scope(
             root_scope,
             &mut root,
             &mut hydrated,
             dom,
             &mut last_node_was_static_text,
-        )?;
+        );
+
+        // The dom is rebuilt from scratch if rehydration fails, which mounts every element again
+        if rehydrated.is_err() {
+            self.queued_mounted_events.clear();
+            self.visibility.clear_queue();
+            self.sizes.clear_queue();
+        }
+        rehydrated?;
 
         self.interpreter.flush();
+        self.flush_queued_listeners();
         Ok(())
     }
 
@@ -128,11 +136,7 @@ impl WebsysDom {
                         mounted_id = Some(id);
                         let name = attribute.name;
                         if let AttributeValue::Listener(_) = value {
-                            self.interpreter.new_event_listener(
-                                &name[2..],
-                                id.0 as u32,
-                                event_bubbles(name) as u8,
-                            );
+                            self.add_listener(&name[2..], id);
                         }
                     }
                 }
@@ -0,0 +1,169 @@
+//! Helpers for building lists that can be reordered with drag and drop
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use dioxus_core::ScopeState;
+use dioxus_html::DragData;
+
+/// Track a drag and drop interaction that reorders the children of a list.
+///
+/// The list container must have the given `id`, and each of its element children is treated as one item of the list,
+/// in order. Wire the returned handle up to the drag events of the items and the list:
+///
+/// - call [`UseDragReorder::start`] from `ondragstart` of the item being dragged
+/// - call [`UseDragReorder::over`] from `ondragover` of the list
+/// - call [`UseDragReorder::drop`] from `ondrop` of the list
+/// - call [`UseDragReorder::cancel`] from `ondragend` of the items
+///
+/// When an item is dropped in a new position, `on_move` is called with the index the item came from and the index it
+/// should end up at once it has been removed from its old position. Dropping an item outside of the list or back in the
+/// same position doesn't call `on_move`.
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let items = use_state(cx, || vec!["a", "b", "c"]);
+///     let reorder = use_drag_reorder(cx, "list", {
+///         to_owned![items];
+///         move |from, to| items.with_mut(|items| {
+///             let item = items.remove(from);
+///             items.insert(to, item);
+///         })
+///     });
+///
+///     cx.render(rsx! {
+///         ul {
+///             id: "list",
+///             prevent_default: "ondragover",
+///             ondragover: move |evt| { reorder.over(&evt.data); },
+///             ondrop: move |_| reorder.drop(),
+///             items.iter().enumerate().map(|(i, item)| rsx! {
+///                 li {
+///                     key: "{item}",
+///                     draggable: "true",
+///                     ondragstart: move |_| reorder.start(i),
+///                     ondragend: move |_| reorder.cancel(),
+///                     "{item}"
+///                 }
+///             })
+///         }
+///     })
+/// }
+/// ```
+pub fn use_drag_reorder(
+    cx: &ScopeState,
+    container_id: impl ToString,
+    on_move: impl Fn(usize, usize) + 'static,
+) -> &UseDragReorder {
+    let hook = cx.use_hook(|| UseDragReorder {
+        container_id: container_id.to_string(),
+        source: Rc::new(Cell::new(None)),
+        target: Rc::new(Cell::new(None)),
+        on_move: Rc::new(RefCell::new(
+            Box::new(|_, _| {}) as Box<dyn Fn(usize, usize)>
+        )),
+    });
+
+    // Always call the latest callback so it can capture fresh state
+    *hook.on_move.borrow_mut() = Box::new(on_move);
+
+    hook
+}
+
+/// A handle to the state of a drag and drop reorder interaction.
+///
+/// Created with [`use_drag_reorder`].
+#[derive(Clone)]
+pub struct UseDragReorder {
+    container_id: String,
+    source: Rc<Cell<Option<usize>>>,
+    target: Rc<Cell<Option<usize>>>,
+    on_move: Rc<RefCell<Box<dyn Fn(usize, usize)>>>,
+}
+
+impl UseDragReorder {
+    /// Start dragging the item at the given index.
+    pub fn start(&self, index: usize) {
+        self.source.set(Some(index));
+        self.target.set(None);
+    }
+
+    /// Update the drop target from the position of a `dragover` event.
+    ///
+    /// Returns the index the dragged item would end up at if it was dropped now, or `None` if nothing is being dragged
+    /// or the pointer is outside of the list.
+    pub fn over(&self, evt: &DragData) -> Option<usize> {
+        let source = self.source.get()?;
+        let point = evt.mouse.client_coordinates();
+        let target = self
+            .insertion_point(point.x, point.y)
+            // The item is removed from its old position before being inserted, which shifts everything after it
+            .map(|index| if index > source { index - 1 } else { index });
+
+        self.target.set(target);
+        target
+    }
+
+    /// Finish the drag, calling `on_move` if the item was dropped in a new position inside the list.
+    pub fn drop(&self) {
+        let source = self.source.take();
+        let target = self.target.take();
+
+        if let (Some(from), Some(to)) = (source, target) {
+            if from != to {
+                (self.on_move.borrow())(from, to);
+            }
+        }
+    }
+
+    /// Cancel the drag without moving anything.
+    ///
+    /// This is safe to call after [`UseDragReorder::drop`], since the drag will already have been finished.
+    pub fn cancel(&self) {
+        self.source.set(None);
+        self.target.set(None);
+    }
+
+    /// The index of the item being dragged, if any.
+    pub fn source(&self) -> Option<usize> {
+        self.source.get()
+    }
+
+    /// The index the dragged item would be moved to if it was dropped now, if any.
+    pub fn target(&self) -> Option<usize> {
+        self.target.get()
+    }
+
+    /// Find the index of the child that the point falls before, measuring each child of the list.
+    ///
+    /// Returns `None` if the list can't be found or the point is outside of it.
+    fn insertion_point(&self, x: f64, y: f64) -> Option<usize> {
+        let container = web_sys::window()?
+            .document()?
+            .get_element_by_id(&self.container_id)?;
+
+        let bounds = container.get_bounding_client_rect();
+        if x < bounds.left() || x > bounds.right() || y < bounds.top() || y > bounds.bottom() {
+            return None;
+        }
+
+        let children = container.children();
+        let len = children.length();
+
+        for index in 0..len {
+            let child = match children.item(index) {
+                Some(child) => child,
+                None => continue,
+            };
+
+            let rect = child.get_bounding_client_rect();
+            if y < rect.top() + rect.height() / 2.0 {
+                return Some(index as usize);
+            }
+        }
+
+        Some(len as usize)
+    }
+}
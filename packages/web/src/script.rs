@@ -0,0 +1,105 @@
+//! Injecting scripts that actually run
+
+use dioxus_core::ScopeState;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlScriptElement;
+
+/// A script to inject into the page with [`use_script`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Script {
+    src: Option<String>,
+    text: Option<String>,
+    ty: Option<String>,
+}
+
+impl Script {
+    /// A script with inline contents
+    pub fn inline(text: impl ToString) -> Self {
+        Self {
+            text: Some(text.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// A script loaded from a url
+    pub fn src(src: impl ToString) -> Self {
+        Self {
+            src: Some(src.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the `type` of the script, like `module` or `application/ld+json`
+    pub fn with_type(mut self, ty: impl ToString) -> Self {
+        self.ty = Some(ty.to_string());
+        self
+    }
+}
+
+/// Inject a script into the head of the document when the component is mounted, and remove it when the component is
+/// dropped.
+///
+/// Browsers never run scripts that are added through `dangerous_inner_html`, and a `script` element rendered in rsx
+/// doesn't run again if its contents change. This hook creates the script element itself, so the script runs exactly
+/// once when the component mounts.
+///
+/// # Safety
+///
+/// The script runs with full access to the page and everything your app has access to. Never build a script out of
+/// user input, since that is an XSS vulnerability waiting to happen.
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     use_script(cx, || Script::src("https://example.com/analytics.js"));
+///     use_script(cx, || Script::inline(r#"{ "@type": "Organization" }"#).with_type("application/ld+json"));
+///
+///     cx.render(rsx! { "hello" })
+/// }
+/// ```
+pub fn use_script(cx: &ScopeState, script: impl FnOnce() -> Script) {
+    cx.use_hook(|| InjectedScript::new(script()));
+}
+
+struct InjectedScript {
+    element: Option<HtmlScriptElement>,
+}
+
+impl InjectedScript {
+    fn new(script: Script) -> Self {
+        let element = create_script(&script);
+
+        if element.is_none() {
+            log::warn!("Failed to inject script {:?}", script);
+        }
+
+        Self { element }
+    }
+}
+
+fn create_script(script: &Script) -> Option<HtmlScriptElement> {
+    let document = web_sys::window()?.document()?;
+    let element: HtmlScriptElement = document.create_element("script").ok()?.dyn_into().ok()?;
+
+    if let Some(ty) = &script.ty {
+        element.set_type(ty);
+    }
+    if let Some(src) = &script.src {
+        element.set_src(src);
+    }
+    if let Some(text) = &script.text {
+        _ = element.set_text(text);
+    }
+
+    // A script created by us (rather than the parser) runs as soon as it's connected to the document
+    document.head()?.append_child(&element).ok()?;
+
+    Some(element)
+}
+
+impl Drop for InjectedScript {
+    fn drop(&mut self) {
+        if let Some(element) = &self.element {
+            element.remove();
+        }
+    }
+}
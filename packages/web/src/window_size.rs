@@ -0,0 +1,119 @@
+//! Tracking the size of the browser window
+
+use std::{cell::Cell, rc::Rc};
+
+use dioxus_core::ScopeState;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::Window;
+
+/// Get the current inner width and height of the browser window, re-rendering the component when the window is
+/// resized.
+///
+/// Resize events are coalesced to at most one re-render per animation frame, so rapidly resizing the window doesn't
+/// flood the VirtualDom with updates. The listener is removed when the component is dropped.
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let (width, height) = use_window_size(cx);
+///
+///     cx.render(rsx! { "The window is {width}x{height}" })
+/// }
+/// ```
+pub fn use_window_size(cx: &ScopeState) -> (f64, f64) {
+    let hook = cx.use_hook(|| WindowSize::new(cx));
+    hook.size.get()
+}
+
+struct WindowSize {
+    size: Rc<Cell<(f64, f64)>>,
+    window: Option<Window>,
+    on_resize: Closure<dyn FnMut()>,
+    // The animation frame we're waiting on to read the new size, if any
+    pending_frame: Rc<Cell<Option<i32>>>,
+    // Keep the frame callback alive, it's reused for every frame we request
+    _on_frame: Closure<dyn FnMut()>,
+}
+
+impl WindowSize {
+    fn new(cx: &ScopeState) -> Self {
+        let window = web_sys::window();
+        let size = Rc::new(Cell::new(
+            window.as_ref().map(read_size).unwrap_or_default(),
+        ));
+        let pending_frame = Rc::new(Cell::new(None));
+
+        // Read the size once per frame, and only re-render if it changed
+        let on_frame = Closure::wrap(Box::new({
+            let size = size.clone();
+            let pending_frame = pending_frame.clone();
+            let update = cx.schedule_update();
+            move || {
+                pending_frame.set(None);
+                if let Some(window) = web_sys::window() {
+                    let new_size = read_size(&window);
+                    if new_size != size.get() {
+                        size.set(new_size);
+                        update();
+                    }
+                }
+            }
+        }) as Box<dyn FnMut()>);
+
+        let on_resize = Closure::wrap(Box::new({
+            let on_frame = on_frame.as_ref().clone();
+            let pending_frame = pending_frame.clone();
+            move || {
+                if pending_frame.get().is_some() {
+                    return;
+                }
+                if let Some(window) = web_sys::window() {
+                    pending_frame.set(
+                        window
+                            .request_animation_frame(on_frame.unchecked_ref())
+                            .ok(),
+                    );
+                }
+            }
+        }) as Box<dyn FnMut()>);
+
+        if let Some(window) = &window {
+            _ = window
+                .add_event_listener_with_callback("resize", on_resize.as_ref().unchecked_ref());
+        }
+
+        Self {
+            size,
+            window,
+            on_resize,
+            pending_frame,
+            _on_frame: on_frame,
+        }
+    }
+}
+
+impl Drop for WindowSize {
+    fn drop(&mut self) {
+        if let Some(window) = &self.window {
+            _ = window.remove_event_listener_with_callback(
+                "resize",
+                self.on_resize.as_ref().unchecked_ref(),
+            );
+
+            // The frame callback is about to be dropped, so make sure the browser doesn't call it
+            if let Some(frame) = self.pending_frame.take() {
+                _ = window.cancel_animation_frame(frame);
+            }
+        }
+    }
+}
+
+fn read_size(window: &Window) -> (f64, f64) {
+    let read = |value: Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue>| {
+        value
+            .ok()
+            .and_then(|value| value.as_f64())
+            .unwrap_or_default()
+    };
+
+    (read(window.inner_width()), read(window.inner_height()))
+}
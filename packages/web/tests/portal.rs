@@ -0,0 +1,55 @@
+use dioxus::prelude::*;
+use dioxus_web::{use_portal, Config};
+use gloo_timers::future::TimeoutFuture;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::{window, Document, HtmlElement};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn document() -> Document {
+    window().unwrap().document().unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn removing_the_parent_cleans_up_the_body() {
+    fn app(cx: Scope) -> Element {
+        let portal = use_portal(cx, "body");
+        let show = use_state(cx, || true);
+
+        cx.render(rsx! {
+            button { id: "hide-modal", onclick: move |_| show.set(false), "hide" }
+            show.then(|| rsx! {
+                div { hidden: true,
+                    div { class: "portal-modal", onmounted: move |evt| portal.adopt(evt.id),
+                        "rendered at the end of the body"
+                    }
+                }
+            })
+        })
+    }
+
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    wasm_bindgen_futures::spawn_local(dioxus_web::run_with_props(
+        app,
+        (),
+        Config::new().with_root_element(root.clone()),
+    ));
+    TimeoutFuture::new(50).await;
+
+    // The modal was moved out of the root and into the body
+    let modal = document().query_selector(".portal-modal").unwrap();
+    assert!(modal.is_some());
+    assert!(root.query_selector(".portal-modal").unwrap().is_none());
+
+    // Removing the element it was rendered in removes the modal from the body too
+    let hide = document().get_element_by_id("hide-modal").unwrap();
+    hide.dyn_into::<HtmlElement>().unwrap().click();
+    TimeoutFuture::new(50).await;
+
+    assert!(document()
+        .query_selector(".portal-modal")
+        .unwrap()
+        .is_none());
+}